@@ -1,10 +1,21 @@
-use crate::views::{theme_view, upgrade_context_view};
+use crate::view_models::{
+    game_data_view_model::GameDataViewModel, upgrade_context_view_model::UpgradeContextViewModel,
+};
+use crate::views::{game_data_view, starforce_view, theme_view, upgrade_context_view};
 use sycamore::prelude::*;
 
-#[component]
-pub fn App() -> View {
+#[component(inline_props)]
+pub fn App(game_data_installed: Result<bool, String>) -> View {
+    let game_data_view_model = GameDataViewModel::new(game_data_installed);
+    provide_context(game_data_view_model);
+
+    let upgrade_context_view_model = UpgradeContextViewModel::new();
+    provide_context(upgrade_context_view_model);
+
     view! {
         theme_view::ThemeView()
+        game_data_view::GameDataView()
         upgrade_context_view::UpgradeContextView()
+        starforce_view::StarforceView()
     }
 }
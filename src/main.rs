@@ -4,6 +4,11 @@ mod utils;
 mod view_models;
 mod views;
 
+use sycamore::prelude::*;
+use view_models::game_data_view_model::GameDataViewModel;
+
 fn main() {
-    sycamore::render(app::App);
+    let game_data_installed = GameDataViewModel::install_stored_data();
+
+    sycamore::render(|| view! { app::App(game_data_installed=game_data_installed) });
 }
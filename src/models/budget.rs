@@ -0,0 +1,347 @@
+use crate::models::{
+    equipment::{self, SuccessBonus},
+    golden_hammer,
+    innocent::{self, InnocentScroll},
+    strategy::{self, Action, CleanSlate, PolicyRow, ScrollOption, State},
+    upgrade_context::UpgradeContext,
+    upgrade_cost,
+};
+
+mod constants {
+    pub const MIN_BUDGET_STEPS: usize = 100;
+    pub const MAX_BUDGET_STEPS: usize = 1000;
+    pub const MAX_CELLS: usize = 500_000;
+    pub const MESO_PER_BUDGET_UNIT: f64 = 10_000.0;
+    pub const TOLERANCE: f64 = 1e-9;
+}
+
+pub struct BudgetPlan {
+    pub probability: f64,
+    pub policy: Vec<PolicyRow>,
+}
+
+#[derive(Clone, Copy)]
+enum Step {
+    Scroll(usize),
+    Restore,
+    Reset,
+}
+
+struct BudgetSolver {
+    steps: usize,
+    options: Vec<ScrollOption>,
+    option_steps: Vec<(usize, f64)>,
+    clean_slate: Option<(CleanSlate, usize)>,
+    innocent: Option<(InnocentScroll, usize)>,
+    hammer_steps: usize,
+    bonus: Option<SuccessBonus>,
+    slots: usize,
+    target: usize,
+    probabilities: Vec<f64>,
+}
+
+impl BudgetSolver {
+    fn pairs(&self) -> usize {
+        (self.slots + 1) * (self.slots + 2) / 2
+    }
+
+    fn index(&self, budget: usize, state: State) -> usize {
+        let successes = state.successes;
+        let pair = successes * (self.slots + 1) - successes * successes.saturating_sub(1) / 2
+            + state.remaining;
+
+        (budget * self.pairs() + pair) * self.target + state.gained
+    }
+
+    fn full(&self) -> State {
+        State {
+            remaining: self.slots,
+            successes: 0,
+            gained: 0,
+        }
+    }
+
+    fn value(&self, budget: usize, state: State) -> f64 {
+        if state.gained >= self.target {
+            1.0
+        } else {
+            self.probabilities[self.index(budget, state)]
+        }
+    }
+
+    fn start(&self, budget: usize) -> f64 {
+        budget.checked_sub(self.hammer_steps).map_or(0.0, |budget| self.value(budget, self.full()))
+    }
+
+    fn solve(&mut self) {
+        for budget in 0..=self.steps {
+            for remaining in 0..=self.slots {
+                for successes in 0..=self.slots - remaining {
+                    for gained in 0..self.target {
+                        let state = State {
+                            remaining,
+                            successes,
+                            gained,
+                        };
+                        let probability = self
+                            .candidates(budget, state)
+                            .map(|(probability, _)| probability)
+                            .fold(0.0, f64::max);
+                        let index = self.index(budget, state);
+
+                        self.probabilities[index] = probability;
+                    }
+                }
+            }
+        }
+    }
+
+    fn candidates(&self, budget: usize, state: State) -> impl Iterator<Item = (f64, Step)> {
+        let scrolls = (0..self.options.len()).filter_map(move |index| {
+            Some((self.scroll_value(budget, state, index)?, Step::Scroll(index)))
+        });
+        let restore =
+            self.restore_value(budget, state).map(|probability| (probability, Step::Restore));
+        let reset = self.reset_value(budget, state).map(|probability| (probability, Step::Reset));
+
+        scrolls.chain(restore).chain(reset)
+    }
+
+    fn scroll_value(&self, budget: usize, state: State, index: usize) -> Option<f64> {
+        if state.remaining == 0 {
+            return None;
+        }
+
+        let option = &self.options[index];
+        let (whole, fraction) = self.option_steps[index];
+        let transition = option.transition;
+        let succeeded_state = strategy::succeeded_state(state, option, self.bonus, self.target);
+        let failed_state = strategy::failed_state(state);
+        let mut value = 0.0;
+        let mut looped = 0.0;
+
+        budget.checked_sub(whole)?;
+
+        for (steps, weight) in [(whole, 1.0 - fraction), (whole + 1, fraction)] {
+            let Some(next_budget) = budget.checked_sub(steps).filter(|_| weight > 0.0) else {
+                continue;
+            };
+
+            value += weight
+                * (transition.success * self.value(next_budget, succeeded_state)
+                    + transition.slot_lost * self.value(next_budget, failed_state));
+
+            if next_budget == budget {
+                looped += weight * transition.slot_kept;
+            } else {
+                value += weight * transition.slot_kept * self.value(next_budget, state);
+            }
+        }
+
+        (looped < 1.0).then(|| value / (1.0 - looped))
+    }
+
+    fn restore_value(&self, budget: usize, state: State) -> Option<f64> {
+        let (clean_slate, steps) = self.clean_slate?;
+
+        if state.remaining > 0 || state.successes >= self.slots {
+            return None;
+        }
+
+        let next_budget = budget.checked_sub(steps)?;
+        let restored = State {
+            remaining: 1,
+            ..state
+        };
+
+        Some(
+            clean_slate.success * self.value(next_budget, restored)
+                + (1.0 - clean_slate.success) * self.value(next_budget, state),
+        )
+    }
+
+    fn reset_value(&self, budget: usize, state: State) -> Option<f64> {
+        let (innocent, steps) = self.innocent?;
+
+        if state.remaining == self.slots && state.gained == 0 {
+            return None;
+        }
+
+        let next_budget = budget.checked_sub(steps)?;
+
+        Some(
+            innocent.success * self.start(next_budget)
+                + (1.0 - innocent.success) * self.value(next_budget, state),
+        )
+    }
+
+    fn action(&self, budget: usize, state: State) -> Option<Action> {
+        let (_, step) = self
+            .candidates(budget, state)
+            .filter(|(probability, _)| *probability > 0.0)
+            .max_by(|(a, _), (b, _)| a.total_cmp(b))?;
+
+        match step {
+            Step::Scroll(index) => Some(Action::Scroll(index)),
+            Step::Restore => {
+                let (_, steps) = self.clean_slate?;
+                let restored = State {
+                    remaining: 1,
+                    ..state
+                };
+
+                match self.action(budget.checked_sub(steps)?, restored)? {
+                    Action::Scroll(index) => Some(Action::Restore(index)),
+                    _ => None,
+                }
+            }
+            Step::Reset => Some(Action::Reset),
+        }
+    }
+
+    fn policy(&self) -> Vec<PolicyRow> {
+        let Some(budget) = self.steps.checked_sub(self.hammer_steps) else {
+            return Vec::new();
+        };
+
+        strategy::policy(
+            &self.options,
+            self.innocent.map(|(innocent, _)| innocent),
+            self.bonus,
+            (self.slots, self.target),
+            |state| self.action(budget, state),
+        )
+    }
+}
+
+#[allow(clippy::cast_precision_loss)]
+pub fn budget_plan(context: &UpgradeContext) -> Option<BudgetPlan> {
+    let budget = budget_meso(context.meso_budget?);
+    let slots = golden_hammer::slot_count(context)? as usize;
+    let target = context.target_stat? as usize;
+    let options = strategy::scroll_options(context)?;
+    let pairs = (slots + 1) * (slots + 2) / 2;
+    let steps = (constants::MAX_CELLS / (pairs * target))
+        .clamp(constants::MIN_BUDGET_STEPS, constants::MAX_BUDGET_STEPS);
+    let unit = budget / steps as f64;
+    let whole_steps = |meso: f64| {
+        let (whole, fraction) = budget_steps(meso, unit, steps);
+        whole + usize::from(fraction > 0.0)
+    };
+
+    let mut solver = BudgetSolver {
+        steps,
+        option_steps: options.iter().map(|option| budget_steps(option.cost, unit, steps)).collect(),
+        options,
+        clean_slate: strategy::clean_slate(context)
+            .map(|clean_slate| (clean_slate, whole_steps(clean_slate.price).max(1))),
+        innocent: innocent::innocent_scroll(context)
+            .map(|innocent| (innocent, whole_steps(innocent.price).max(1))),
+        hammer_steps: whole_steps(golden_hammer::hammer_cost(context)),
+        bonus: equipment::success_bonus(context),
+        slots,
+        target,
+        probabilities: vec![0.0; (steps + 1) * pairs * target],
+    };
+    solver.solve();
+
+    Some(BudgetPlan {
+        probability: solver.start(steps),
+        policy: solver.policy(),
+    })
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss, clippy::cast_precision_loss)]
+fn budget_steps(meso: f64, unit: f64, steps: usize) -> (usize, f64) {
+    if meso <= 0.0 {
+        return (0, 0.0);
+    }
+
+    if unit <= 0.0 {
+        return (steps + 1, 0.0);
+    }
+
+    let exact = (meso / unit).min((steps + 1) as f64);
+    let whole = (exact + constants::TOLERANCE).floor();
+    let fraction = exact - whole;
+
+    (
+        whole as usize,
+        if fraction > constants::TOLERANCE {
+            fraction
+        } else {
+            0.0
+        },
+    )
+}
+
+pub fn budget_meso(meso_budget: u32) -> f64 {
+    f64::from(meso_budget) * constants::MESO_PER_BUDGET_UNIT
+}
+
+pub fn probability_text(plan: &BudgetPlan) -> String {
+    upgrade_cost::probability_text(plan.probability)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{budget_plan, budget_steps};
+    use crate::models::upgrade_context::UpgradeContext;
+
+    fn context(meso_budget: u32, upgrade_salvation: u32) -> UpgradeContext {
+        UpgradeContext {
+            handicraft: Some(0),
+            enhance_mastery: Some(0),
+            upgrade_salvation: Some(upgrade_salvation),
+            upgradeable_count: Some(1),
+            target_stat: Some(9),
+            trace_price: Some(250),
+            meso_budget: Some(meso_budget),
+            ..UpgradeContext::default()
+        }
+    }
+
+    fn probability(meso_budget: u32, upgrade_salvation: u32) -> f64 {
+        budget_plan(&context(meso_budget, upgrade_salvation)).unwrap().probability
+    }
+
+    #[test]
+    fn budget_steps_split_whole_and_fractional_units() {
+        assert_eq!(budget_steps(0.0, 100.0, 10), (0, 0.0));
+        assert_eq!(budget_steps(300.0, 100.0, 10), (3, 0.0));
+        assert_eq!(budget_steps(100.0, 0.0, 10), (11, 0.0));
+        assert_eq!(budget_steps(5_000.0, 100.0, 10), (11, 0.0));
+
+        let (whole, fraction) = budget_steps(250.0, 100.0, 10);
+        assert_eq!(whole, 2);
+        assert!((fraction - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn budget_below_one_scroll_cannot_succeed() {
+        assert!(probability(9, 0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn single_slot_succeeds_once_per_affordable_scroll() {
+        let success = 0.15;
+        let kept = (1.0 - success) * 0.1;
+
+        assert!((probability(10, 0) - success).abs() < 1e-9);
+        assert!((probability(20, 0) - success).abs() < 1e-9);
+        assert!((probability(20, 10) - (success + kept * success)).abs() < 1e-9);
+        assert!((probability(25, 10) - (success + kept * success)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn policy_lists_the_scroll_for_each_reachable_state() {
+        let plan = budget_plan(&context(10, 0)).unwrap();
+
+        assert_eq!(plan.policy.len(), 1);
+        assert_eq!(plan.policy[0].remaining, 1);
+        assert_eq!(plan.policy[0].ranges.len(), 1);
+        assert_eq!(plan.policy[0].ranges[0].label, "15%");
+
+        assert!(budget_plan(&context(9, 0)).unwrap().policy.is_empty());
+    }
+}
@@ -0,0 +1,96 @@
+use crate::models::{
+    distribution::Distribution, event, game_data::game_data, golden_hammer, innocent,
+    slot_transition::SlotTransition, success_rate, target_stat, upgrade_context::UpgradeContext,
+    upgrade_cost,
+};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct ChaosScroll {
+    pub id: u32,
+    pub label: String,
+    pub success_rate: u32,
+    pub max_change: usize,
+    pub stat_count: u32,
+    pub probabilities: Vec<f64>,
+}
+
+pub struct ChaosOutcome {
+    pub attack: Distribution,
+    pub all_stats: Distribution,
+    pub attack_offset: usize,
+    pub all_stats_offset: usize,
+    pub pass_meso: f64,
+    pub target_probability: Option<f64>,
+    pub target_meso: Option<f64>,
+}
+
+pub fn chaos_scrolls() -> &'static [ChaosScroll] {
+    &game_data().chaos_scrolls
+}
+
+pub fn find(id: u32) -> Option<&'static ChaosScroll> {
+    chaos_scrolls().iter().find(|scroll| scroll.id == id)
+}
+
+pub fn change_distribution(scroll: &ChaosScroll) -> Distribution {
+    Distribution::new(scroll.probabilities.clone())
+}
+
+pub fn chaos_outcome(context: &UpgradeContext) -> Option<ChaosOutcome> {
+    let scroll = find(context.chaos_scroll?)?;
+    let price = f64::from(context.chaos_price?);
+    let slots = golden_hammer::slot_count(context)?;
+    let success = success_rate::success_rate(context, scroll.success_rate)?.probability();
+    let transition = SlotTransition::new(success, event::upgrade_salvation(context)?);
+
+    let change = change_distribution(scroll);
+    let all_stats_change = change.repeat(scroll.stat_count);
+    let all_stats_change_offset = scroll.max_change * scroll.stat_count as usize;
+    let attack = slot_distribution(&change, transition, scroll.max_change).repeat(slots);
+    let all_stats =
+        slot_distribution(&all_stats_change, transition, all_stats_change_offset).repeat(slots);
+    let attack_offset = scroll.max_change * slots as usize;
+    let pass_meso = f64::from(slots) * transition.attempts_per_slot() * price
+        + golden_hammer::hammer_cost(context);
+
+    let target_probability = context.target_stat.map(|target| {
+        let below = attack_offset + target as usize;
+        1.0 - below.checked_sub(1).map_or(0.0, |below| attack.cdf(below))
+    });
+    let target_meso = target_probability.and_then(|p| {
+        let reset_meso =
+            innocent::innocent_scroll(context).map_or(0.0, |innocent| innocent.expected_cost());
+        target_stat::per_success_meso(pass_meso, reset_meso, p)
+    });
+
+    Some(ChaosOutcome {
+        attack,
+        all_stats,
+        attack_offset,
+        all_stats_offset: all_stats_change_offset * slots as usize,
+        pass_meso,
+        target_probability,
+        target_meso,
+    })
+}
+
+fn slot_distribution(
+    change: &Distribution,
+    transition: SlotTransition,
+    offset: usize,
+) -> Distribution {
+    Distribution::point(offset).mix(change, transition.successes_per_slot())
+}
+
+#[allow(clippy::cast_precision_loss)]
+pub fn mean_text(distribution: &Distribution, offset: usize) -> String {
+    let mean = ((distribution.mean() - offset as f64) * 100.0).round() / 100.0;
+
+    format!("{:+.2}", mean + 0.0)
+}
+
+#[allow(clippy::cast_possible_wrap)]
+pub fn change_text(value: usize, offset: usize, probability: f64) -> String {
+    format!("{:+}: {}", value as i64 - offset as i64, upgrade_cost::probability_text(probability))
+}
@@ -0,0 +1,255 @@
+mod constants {
+    pub const TAIL_EPSILON: f64 = 1e-12;
+}
+
+#[derive(Clone)]
+pub struct Distribution {
+    probabilities: Vec<f64>,
+}
+
+impl Distribution {
+    pub fn new(probabilities: Vec<f64>) -> Self {
+        Self {
+            probabilities,
+        }
+    }
+
+    pub fn point(value: usize) -> Self {
+        let mut probabilities = vec![0.0; value + 1];
+        probabilities[value] = 1.0;
+
+        Self {
+            probabilities,
+        }
+    }
+
+    pub fn bernoulli(success: f64, value: usize) -> Self {
+        let mut probabilities = vec![0.0; value + 1];
+        probabilities[0] += 1.0 - success;
+        probabilities[value] += success;
+
+        Self {
+            probabilities,
+        }
+    }
+
+    pub fn geometric(success: f64) -> Self {
+        let mut probabilities = vec![0.0];
+        let mut remaining = 1.0;
+
+        while remaining > constants::TAIL_EPSILON {
+            let probability = remaining * success;
+            probabilities.push(probability);
+            remaining -= probability;
+        }
+
+        Self {
+            probabilities,
+        }
+    }
+
+    pub fn convolve(&self, other: &Self) -> Self {
+        let mut probabilities = vec![0.0; self.probabilities.len() + other.probabilities.len() - 1];
+
+        for (i, p) in self.probabilities.iter().enumerate().filter(|(_, p)| **p > 0.0) {
+            for (j, q) in other.probabilities.iter().enumerate() {
+                probabilities[i + j] += p * q;
+            }
+        }
+
+        Self {
+            probabilities,
+        }
+    }
+
+    pub fn repeat(&self, count: u32) -> Self {
+        (0..count).fold(Self::point(0), |sum, _| sum.convolve(self))
+    }
+
+    pub fn mix(&self, other: &Self, weight: f64) -> Self {
+        let length = self.probabilities.len().max(other.probabilities.len());
+        let probabilities = (0..length)
+            .map(|value| {
+                let p = self.probabilities.get(value).copied().unwrap_or_default();
+                let q = other.probabilities.get(value).copied().unwrap_or_default();
+
+                p * (1.0 - weight) + q * weight
+            })
+            .collect();
+
+        Self {
+            probabilities,
+        }
+    }
+
+    pub fn map<F>(&self, f: F) -> Self
+    where
+        F: Fn(usize) -> usize,
+    {
+        let mut probabilities = Vec::new();
+
+        for (value, p) in self.probabilities.iter().enumerate() {
+            let mapped = f(value);
+
+            if probabilities.len() <= mapped {
+                probabilities.resize(mapped + 1, 0.0);
+            }
+
+            probabilities[mapped] += p;
+        }
+
+        Self {
+            probabilities,
+        }
+    }
+
+    pub fn at_least(&self, threshold: usize) -> Option<Self> {
+        let kept = 1.0 - threshold.checked_sub(1).map_or(0.0, |below| self.cdf(below));
+        let probabilities = self
+            .probabilities
+            .iter()
+            .enumerate()
+            .map(|(value, p)| {
+                if value >= threshold {
+                    p / kept
+                } else {
+                    0.0
+                }
+            })
+            .collect();
+
+        (kept > 0.0).then_some(Self {
+            probabilities,
+        })
+    }
+
+    pub fn support(&self) -> impl Iterator<Item = (usize, f64)> {
+        self.probabilities.iter().copied().enumerate().filter(|(_, p)| *p > constants::TAIL_EPSILON)
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    pub fn mean(&self) -> f64 {
+        self.probabilities.iter().enumerate().map(|(value, p)| value as f64 * p).sum()
+    }
+
+    pub fn cdf(&self, value: usize) -> f64 {
+        self.probabilities.iter().take(value.saturating_add(1)).sum()
+    }
+
+    pub fn percentile(&self, percentile: f64) -> usize {
+        let mut cumulative = 0.0;
+
+        for (value, p) in self.probabilities.iter().enumerate() {
+            cumulative += p;
+
+            if cumulative >= percentile {
+                return value;
+            }
+        }
+
+        self.probabilities.len() - 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Distribution;
+
+    const EPSILON: f64 = 1e-9;
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!((actual - expected).abs() < EPSILON, "{actual} != {expected}");
+    }
+
+    #[test]
+    fn geometric_cdf_matches_closed_form() {
+        for success in [1.0, 0.7, 0.3, 0.15] {
+            let distribution = Distribution::geometric(success);
+
+            let mut failed = 1.0;
+
+            for attempts in 1..=5 {
+                failed *= 1.0 - success;
+                assert_close(distribution.cdf(attempts), 1.0 - failed);
+            }
+            assert_close(distribution.cdf(usize::MAX), 1.0);
+        }
+    }
+
+    #[test]
+    fn repeat_convolves_independent_copies() {
+        let distribution = Distribution::geometric(0.5).repeat(2);
+
+        for (probability, expected) in
+            distribution.probabilities.iter().zip([0.0, 0.0, 4.0, 4.0, 3.0])
+        {
+            assert_close(*probability, expected / 16.0);
+        }
+        assert_close(Distribution::point(3).convolve(&Distribution::point(2)).cdf(4), 0.0);
+        assert_close(Distribution::point(3).convolve(&Distribution::point(2)).cdf(5), 1.0);
+    }
+
+    #[test]
+    fn percentile_is_smallest_value_reaching_cumulative_probability() {
+        let distribution = Distribution {
+            probabilities: vec![0.1, 0.2, 0.3, 0.4],
+        };
+
+        assert_eq!(distribution.percentile(0.1), 0);
+        assert_eq!(distribution.percentile(0.25), 1);
+        assert_eq!(distribution.percentile(0.6), 2);
+        assert_eq!(distribution.percentile(0.99), 3);
+    }
+
+    #[test]
+    fn geometric_mean_is_inverse_of_success() {
+        for success in [1.0, 0.7, 0.3, 0.15] {
+            assert!((Distribution::geometric(success).mean() - 1.0 / success).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn repeated_bernoulli_is_binomial() {
+        let distribution = Distribution::bernoulli(0.5, 1).repeat(4);
+
+        for (probability, expected) in
+            distribution.probabilities.iter().zip([1.0, 4.0, 6.0, 4.0, 1.0])
+        {
+            assert_close(*probability, expected / 16.0);
+        }
+        assert_close(distribution.mean(), 2.0);
+    }
+
+    #[test]
+    fn at_least_renormalises_kept_values() {
+        let distribution = Distribution {
+            probabilities: vec![0.1, 0.2, 0.3, 0.4],
+        }
+        .at_least(2)
+        .unwrap();
+
+        assert_close(distribution.cdf(1), 0.0);
+        assert_close(distribution.cdf(2), 3.0 / 7.0);
+        assert_close(distribution.cdf(3), 1.0);
+        assert!(Distribution::point(1).at_least(2).is_none());
+    }
+
+    #[test]
+    fn map_merges_values_sent_to_the_same_bucket() {
+        let mapped = Distribution::bernoulli(0.5, 3).map(|value| value.min(2));
+
+        assert_close(mapped.cdf(0), 0.5);
+        assert_close(mapped.cdf(1), 0.5);
+        assert_close(mapped.cdf(2), 1.0);
+        assert_close(mapped.mean(), 1.0);
+    }
+
+    #[test]
+    fn mix_weights_both_distributions() {
+        let mixed = Distribution::point(0).mix(&Distribution::point(4), 0.25);
+
+        assert_close(mixed.cdf(0), 0.75);
+        assert_close(mixed.cdf(4), 1.0);
+        assert_close(mixed.mean(), 1.0);
+    }
+}
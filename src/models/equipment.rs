@@ -0,0 +1,290 @@
+use crate::models::{
+    game_data::game_data,
+    spell_trace::{self, SpellTrace},
+    upgrade_context::UpgradeContext,
+};
+use serde::Deserialize;
+
+pub struct EquipmentCategory {
+    pub id: u32,
+    pub label: &'static str,
+}
+
+pub struct StatKind {
+    pub id: u32,
+    pub label: &'static str,
+}
+
+#[derive(Deserialize)]
+struct ScrollRule {
+    success_rate: u32,
+    stats: Vec<u32>,
+}
+
+#[derive(Deserialize)]
+pub struct CategoryRule {
+    category: u32,
+    scrolls: Vec<ScrollRule>,
+}
+
+#[derive(Deserialize)]
+pub struct StatGain {
+    category: u32,
+    bracket: LevelBracket,
+    stat: u32,
+    gains: Vec<u32>,
+}
+
+impl CategoryRule {
+    pub fn is_empty(&self) -> bool {
+        self.scrolls.is_empty()
+    }
+}
+
+impl StatGain {
+    pub fn tiers(&self) -> usize {
+        self.gains.len()
+    }
+}
+
+#[derive(Deserialize)]
+pub struct BonusRule {
+    category: u32,
+    success: u32,
+    stat: u32,
+    amount: u32,
+}
+
+#[derive(Clone, Copy)]
+pub struct SuccessBonus {
+    pub successes: usize,
+    pub amount: u32,
+}
+
+#[derive(Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LevelBracket {
+    Low,
+    Middle,
+    High,
+}
+
+pub const WEAPON: u32 = 0;
+pub const ARMOR: u32 = 1;
+pub const ACCESSORY: u32 = 2;
+pub const GLOVES: u32 = 3;
+pub const HEART: u32 = 4;
+pub const PET_EQUIPMENT: u32 = 5;
+
+pub const EQUIPMENT_CATEGORIES: [EquipmentCategory; 6] = [
+    EquipmentCategory {
+        id: WEAPON,
+        label: "무기",
+    },
+    EquipmentCategory {
+        id: ARMOR,
+        label: "방어구",
+    },
+    EquipmentCategory {
+        id: ACCESSORY,
+        label: "장신구",
+    },
+    EquipmentCategory {
+        id: GLOVES,
+        label: "장갑",
+    },
+    EquipmentCategory {
+        id: HEART,
+        label: "기계 심장",
+    },
+    EquipmentCategory {
+        id: PET_EQUIPMENT,
+        label: "펫장비",
+    },
+];
+
+pub const ATTACK: u32 = 0;
+pub const MAIN_STAT: u32 = 1;
+pub const MAX_HP: u32 = 2;
+
+pub const STAT_KINDS: [StatKind; 3] = [
+    StatKind {
+        id: ATTACK,
+        label: "공격력/마력",
+    },
+    StatKind {
+        id: MAIN_STAT,
+        label: "주스탯",
+    },
+    StatKind {
+        id: MAX_HP,
+        label: "최대 HP",
+    },
+];
+
+pub fn find(id: u32) -> Option<&'static EquipmentCategory> {
+    EQUIPMENT_CATEGORIES.iter().find(|category| category.id == id)
+}
+
+pub fn level_bracket(equipment_level: u32) -> LevelBracket {
+    let brackets = &game_data().level_brackets;
+
+    if equipment_level <= brackets.low_max {
+        LevelBracket::Low
+    } else if equipment_level <= brackets.middle_max {
+        LevelBracket::Middle
+    } else {
+        LevelBracket::High
+    }
+}
+
+pub fn context_bracket(context: &UpgradeContext) -> Option<(u32, LevelBracket)> {
+    let category = find(context.equipment_category?)?;
+
+    Some((category.id, level_bracket(context.equipment_level?)))
+}
+
+fn category_rule(context: &UpgradeContext) -> Option<&'static CategoryRule> {
+    let category = context.equipment_category?;
+
+    game_data().category_rules.iter().find(|rule| rule.category == category)
+}
+
+fn scroll_rule(context: &UpgradeContext, spell_trace: &SpellTrace) -> Option<&'static ScrollRule> {
+    category_rule(context)?
+        .scrolls
+        .iter()
+        .find(|scroll| scroll.success_rate == spell_trace.success_rate)
+}
+
+pub fn allows(context: &UpgradeContext, spell_trace: &SpellTrace) -> bool {
+    category_rule(context).is_none() || scroll_rule(context, spell_trace).is_some()
+}
+
+pub fn available_spell_traces(context: &UpgradeContext) -> Vec<&'static SpellTrace> {
+    spell_trace::spell_traces().iter().filter(|spell_trace| allows(context, spell_trace)).collect()
+}
+
+pub fn available_stats(context: &UpgradeContext) -> Vec<&'static StatKind> {
+    STAT_KINDS
+        .iter()
+        .filter(|stat| match category_rule(context) {
+            Some(rule) => {
+                rule.scrolls.iter().any(|scroll| scroll.stats.contains(&stat.id))
+                    || bonus_rule(rule.category, stat.id).is_some()
+            }
+            None => true,
+        })
+        .collect()
+}
+
+pub fn target_stat_kind(context: &UpgradeContext) -> Option<u32> {
+    let available = available_stats(context);
+
+    context
+        .target_stat_kind
+        .filter(|&id| available.iter().any(|stat| stat.id == id))
+        .or_else(|| available.first().map(|stat| stat.id))
+}
+
+pub fn stat_gain(context: &UpgradeContext, spell_trace: &SpellTrace) -> Option<u32> {
+    if category_rule(context).is_none() {
+        return Some(spell_trace.stat_gain);
+    }
+
+    let stat = target_stat_kind(context)?;
+    let granted =
+        scroll_rule(context, spell_trace).is_some_and(|scroll| scroll.stats.contains(&stat));
+
+    if !granted {
+        return Some(0);
+    }
+
+    let (category, bracket) = context_bracket(context)?;
+    let tier = spell_trace::tier(spell_trace)?;
+
+    game_data()
+        .stat_gains
+        .iter()
+        .find(|gain| gain.category == category && gain.bracket == bracket && gain.stat == stat)?
+        .gains
+        .get(tier)
+        .copied()
+}
+
+fn bonus_rule(category: u32, stat: u32) -> Option<&'static BonusRule> {
+    game_data()
+        .success_bonuses
+        .iter()
+        .find(|bonus| bonus.category == category && bonus.stat == stat)
+}
+
+pub fn success_bonus(context: &UpgradeContext) -> Option<SuccessBonus> {
+    let rule = bonus_rule(category_rule(context)?.category, target_stat_kind(context)?)?;
+    let remaining = rule.success.checked_sub(context.existing_successes.unwrap_or_default())?;
+
+    (remaining > 0).then_some(SuccessBonus {
+        successes: remaining as usize,
+        amount: rule.amount,
+    })
+}
+
+pub fn bonus_gain(bonus: Option<SuccessBonus>, successes: usize) -> usize {
+    match bonus {
+        Some(bonus) if successes >= bonus.successes => bonus.amount as usize,
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ARMOR, ATTACK};
+    use crate::models::{spell_trace, stat_outcome, strategy, upgrade_context::UpgradeContext};
+
+    const TRACE_PRICE: u32 = 1_000;
+    const ARMOR_HIGH_TRACES: f64 = 50.0;
+
+    fn armor_attack_context(existing_successes: u32) -> UpgradeContext {
+        UpgradeContext {
+            handicraft: Some(0),
+            enhance_mastery: Some(0),
+            upgrade_salvation: Some(0),
+            equipment_level: Some(150),
+            equipment_category: Some(ARMOR),
+            existing_successes: Some(existing_successes),
+            upgradeable_count: Some(3),
+            spell_trace: Some(100),
+            target_stat_kind: Some(ATTACK),
+            target_stat: Some(1),
+            trace_price: Some(TRACE_PRICE),
+            item_price: Some(0),
+            ..UpgradeContext::default()
+        }
+    }
+
+    #[test]
+    fn bonus_counts_existing_successes() {
+        let context = armor_attack_context(2);
+        let spell_trace = spell_trace::find(100).unwrap();
+        let distribution = stat_outcome::stat_distribution(&context, spell_trace).unwrap();
+
+        assert!(distribution.cdf(0).abs() < 1e-9);
+        assert!((distribution.mean() - 1.0).abs() < 1e-9);
+
+        let strategy = strategy::solve(&context).unwrap();
+        let expected = 2.0 * ARMOR_HIGH_TRACES * f64::from(TRACE_PRICE);
+
+        assert!((strategy.expected_meso - expected).abs() < 1e-3);
+        assert!((strategy.item_success - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn bonus_already_granted_adds_nothing() {
+        let context = armor_attack_context(4);
+        let spell_trace = spell_trace::find(100).unwrap();
+        let distribution = stat_outcome::stat_distribution(&context, spell_trace).unwrap();
+
+        assert!((distribution.cdf(0) - 1.0).abs() < 1e-9);
+        assert!(strategy::solve(&context).is_none());
+    }
+}
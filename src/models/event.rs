@@ -0,0 +1,114 @@
+use crate::models::{
+    strategy, success_rate,
+    upgrade_context::UpgradeContext,
+    upgrade_cost::{self, UpgradeCost},
+};
+
+mod constants {
+    pub const PROTECTED_SALVATION: f64 = 1.0;
+}
+
+pub struct EventModifier {
+    pub trace_discount: u32,
+    pub success_bonus: u32,
+    pub slot_protection: bool,
+}
+
+pub struct EventComparison {
+    pub normal: UpgradeCost,
+    pub event: UpgradeCost,
+    pub normal_target_meso: Option<f64>,
+    pub event_target_meso: Option<f64>,
+}
+
+pub fn event_modifier(context: &UpgradeContext) -> Option<EventModifier> {
+    if !context.event_active.unwrap_or_default() {
+        return None;
+    }
+
+    Some(EventModifier {
+        trace_discount: context.event_trace_discount.unwrap_or_default(),
+        success_bonus: context.event_success_bonus.unwrap_or_default(),
+        slot_protection: context.event_slot_protection.unwrap_or_default(),
+    })
+}
+
+pub fn discounted_traces(context: &UpgradeContext, traces: u32) -> u32 {
+    match event_modifier(context) {
+        Some(modifier) => traces * (100 - modifier.trace_discount) / 100,
+        None => traces,
+    }
+}
+
+pub fn success_bonus(context: &UpgradeContext) -> u32 {
+    event_modifier(context).map_or(0, |modifier| modifier.success_bonus)
+}
+
+pub fn upgrade_salvation(context: &UpgradeContext) -> Option<f64> {
+    match event_modifier(context) {
+        Some(modifier) if modifier.slot_protection => Some(constants::PROTECTED_SALVATION),
+        _ => Some(success_rate::upgrade_salvation_chance(context.upgrade_salvation?) / 100.0),
+    }
+}
+
+pub fn event_comparison(context: &UpgradeContext) -> Option<EventComparison> {
+    let normal = UpgradeContext {
+        event_active: Some(false),
+        ..context.clone()
+    };
+    let event = UpgradeContext {
+        event_active: Some(true),
+        ..context.clone()
+    };
+
+    Some(EventComparison {
+        normal: upgrade_cost::expected_cost(&normal)?,
+        event: upgrade_cost::expected_cost(&event)?,
+        normal_target_meso: strategy::solve(&normal).map(|strategy| strategy.expected_meso),
+        event_target_meso: strategy::solve(&event).map(|strategy| strategy.expected_meso),
+    })
+}
+
+pub fn traces_comparison_text(comparison: &EventComparison) -> String {
+    side_by_side_text(
+        &upgrade_cost::traces_text(comparison.normal.traces),
+        &upgrade_cost::traces_text(comparison.event.traces),
+    )
+}
+
+pub fn meso_comparison_text(comparison: &EventComparison) -> String {
+    side_by_side_text(
+        &upgrade_cost::meso_text(comparison.normal.meso),
+        &upgrade_cost::meso_text(comparison.event.meso),
+    )
+}
+
+pub fn meso_savings_text(comparison: &EventComparison) -> String {
+    savings_text(comparison.normal.meso, comparison.event.meso)
+}
+
+pub fn target_meso_comparison_text(comparison: &EventComparison) -> Option<String> {
+    Some(side_by_side_text(
+        &upgrade_cost::meso_text(comparison.normal_target_meso?),
+        &upgrade_cost::meso_text(comparison.event_target_meso?),
+    ))
+}
+
+pub fn target_meso_savings_text(comparison: &EventComparison) -> Option<String> {
+    Some(savings_text(comparison.normal_target_meso?, comparison.event_target_meso?))
+}
+
+fn side_by_side_text(normal: &str, event: &str) -> String {
+    format!("평상시 {normal} / 이벤트 {event}")
+}
+
+fn savings_text(normal: f64, event: f64) -> String {
+    let savings = normal - event;
+    let ratio = if normal > 0.0 {
+        savings / normal * 100.0
+    } else {
+        0.0
+    };
+
+    format!("{} ({ratio:.1}%)", upgrade_cost::meso_text(savings))
+}
@@ -0,0 +1,187 @@
+use crate::models::{
+    chaos::ChaosScroll,
+    equipment::{BonusRule, CategoryRule, StatGain},
+    pet_equipment::PetEquipment,
+    spell_trace::{SpellTrace, TraceCost},
+    starforce::StarforceData,
+};
+use serde::Deserialize;
+use std::sync::OnceLock;
+
+mod constants {
+    pub const EMBEDDED_GAME_DATA: &str = include_str!("../assets/data/game_data.json");
+}
+
+static GAME_DATA: OnceLock<GameData> = OnceLock::new();
+
+#[derive(Deserialize)]
+pub struct GameData {
+    pub version: String,
+    pub patch_date: String,
+    pub specs: SpecRanges,
+    pub guild_skills: GuildSkills,
+    pub spell_traces: Vec<SpellTrace>,
+    pub level_brackets: LevelBrackets,
+    pub trace_costs: Vec<TraceCost>,
+    pub stat_gains: Vec<StatGain>,
+    pub category_rules: Vec<CategoryRule>,
+    pub success_bonuses: Vec<BonusRule>,
+    pub chaos_scrolls: Vec<ChaosScroll>,
+    pub pet_equipment: PetEquipment,
+    pub starforce: StarforceData,
+}
+
+#[derive(Clone, Copy, Deserialize)]
+pub struct SpecRange {
+    pub min: u32,
+    pub max: u32,
+}
+
+#[derive(Deserialize)]
+pub struct SpecRanges {
+    pub handicraft: SpecRange,
+    pub enhance_mastery: SpecRange,
+    pub upgrade_salvation: SpecRange,
+    pub equipment_level: SpecRange,
+    pub equipment_category: SpecRange,
+    pub existing_successes: SpecRange,
+    pub upgradeable_count: SpecRange,
+    pub spell_trace: SpecRange,
+    pub trace_required: SpecRange,
+    pub trace_limit: SpecRange,
+    pub target_stat_kind: SpecRange,
+    pub target_stat: SpecRange,
+    pub clean_slate_success_rate: SpecRange,
+    pub clean_slate_price: SpecRange,
+    pub innocent_success_rate: SpecRange,
+    pub innocent_price: SpecRange,
+    pub item_price: SpecRange,
+    pub golden_hammer_used: SpecRange,
+    pub golden_hammer_success_rate: SpecRange,
+    pub golden_hammer_price: SpecRange,
+    pub event_active: SpecRange,
+    pub event_trace_discount: SpecRange,
+    pub event_success_bonus: SpecRange,
+    pub event_slot_protection: SpecRange,
+    pub chaos_scroll: SpecRange,
+    pub chaos_price: SpecRange,
+    pub simulation_runs: SpecRange,
+    pub simulation_seed: SpecRange,
+    pub trace_price: SpecRange,
+    pub pet_scroll: SpecRange,
+    pub pet_upgradeable_count: SpecRange,
+    pub pet_scroll_price: SpecRange,
+    pub cash_exchange_rate: SpecRange,
+    pub meso_budget: SpecRange,
+    pub current_star: SpecRange,
+    pub target_star: SpecRange,
+    pub star_catch: SpecRange,
+    pub spare_price: SpecRange,
+    pub starforce_event: SpecRange,
+    pub recovery_method: SpecRange,
+    pub restoration_cost: SpecRange,
+    pub mvp_grade: SpecRange,
+    pub pc_room: SpecRange,
+}
+
+#[derive(Deserialize)]
+pub struct GuildSkills {
+    pub handicraft_step: u32,
+    pub handicraft_bonus_per_step: f64,
+    pub enhance_mastery_bonus_per_level: f64,
+    pub upgrade_salvation_bonus_per_level: f64,
+}
+
+#[derive(Deserialize)]
+pub struct LevelBrackets {
+    pub low_max: u32,
+    pub middle_max: u32,
+}
+
+pub fn game_data() -> &'static GameData {
+    GAME_DATA.get_or_init(embedded)
+}
+
+fn embedded() -> GameData {
+    parse(constants::EMBEDDED_GAME_DATA).unwrap()
+}
+
+pub fn parse(json: &str) -> Result<GameData, String> {
+    let data: GameData = serde_json::from_str(json).map_err(|error| error.to_string())?;
+
+    if data.spell_traces.is_empty() {
+        return Err("spell_traces가 비어 있습니다".to_owned());
+    }
+
+    let tiers = data.spell_traces.len();
+
+    if data.trace_costs.iter().any(|cost| cost.tiers() != tiers)
+        || data.stat_gains.iter().any(|gain| gain.tiers() != tiers)
+    {
+        return Err("단계별 수치의 개수가 주문서 단계 수와 다릅니다".to_owned());
+    }
+
+    if data
+        .chaos_scrolls
+        .iter()
+        .any(|scroll| scroll.probabilities.len() != 2 * scroll.max_change + 1)
+    {
+        return Err("혼돈의 주문서 확률표의 길이가 올바르지 않습니다".to_owned());
+    }
+
+    if data.guild_skills.handicraft_step == 0 {
+        return Err("handicraft_step은 0보다 커야 합니다".to_owned());
+    }
+
+    if data.category_rules.is_empty() || data.category_rules.iter().any(CategoryRule::is_empty) {
+        return Err("category_rules가 비어 있습니다".to_owned());
+    }
+
+    let max_salvation = f64::from(data.specs.upgrade_salvation.max)
+        * data.guild_skills.upgrade_salvation_bonus_per_level;
+
+    if !(0.0..100.0).contains(&max_salvation) {
+        return Err("업그레이드 세이비어 확률은 100% 미만이어야 합니다".to_owned());
+    }
+
+    if data
+        .starforce
+        .stars
+        .iter()
+        .any(|star| star.success <= 0.0 || star.destroy < 0.0 || star.success + star.destroy > 1.0)
+    {
+        return Err("스타포스 확률표의 확률이 올바르지 않습니다".to_owned());
+    }
+
+    if data.starforce.events.iter().any(|event| {
+        !(0.0..=1.0).contains(&event.discount) || !(0.0..=1.0).contains(&event.destroy_reduction)
+    }) {
+        return Err("스타포스 이벤트의 할인율이나 파괴 감소율이 올바르지 않습니다".to_owned());
+    }
+
+    let membership = &data.starforce.membership;
+
+    if membership.mvp_grades.iter().any(|grade| {
+        !(0.0..=1.0).contains(&grade.discount)
+            || !(0.0..=1.0).contains(&(grade.discount + membership.pc_room_discount))
+    }) || !(0.0..=1.0).contains(&membership.pc_room_discount)
+    {
+        return Err("MVP·PC방 할인율이 올바르지 않습니다".to_owned());
+    }
+
+    if data.starforce.destroyed_star as usize >= data.starforce.stars.len() {
+        return Err("destroyed_star는 스타포스 확률표 범위 안이어야 합니다".to_owned());
+    }
+
+    Ok(data)
+}
+
+pub fn install(data: GameData) -> Result<(), String> {
+    GAME_DATA
+        .set(data)
+        .map_err(|_| "게임 데이터를 이미 사용한 뒤라 불러온 파일을 적용할 수 없습니다".to_owned())
+}
+
+pub fn version_text(data: &GameData) -> String {
+    format!("{} ({} 패치 기준)", data.version, data.patch_date)
+}
@@ -0,0 +1,116 @@
+use crate::models::{
+    equipment, spell_trace, strategy, upgrade_context::UpgradeContext, upgrade_cost,
+};
+
+#[derive(Clone, Copy)]
+pub struct GoldenHammer {
+    pub success: f64,
+    pub price: f64,
+}
+
+impl GoldenHammer {
+    pub fn expected_count(&self) -> f64 {
+        1.0 / self.success
+    }
+
+    pub fn expected_cost(&self) -> f64 {
+        self.price * self.expected_count()
+    }
+}
+
+pub struct HammerSlot {
+    pub expected_hammers: f64,
+    pub hammer_meso: f64,
+    pub scroll_meso: f64,
+    pub stat_gain: f64,
+    pub target_meso: Option<f64>,
+    pub target_meso_without_hammer: Option<f64>,
+}
+
+impl HammerSlot {
+    pub fn meso(&self) -> f64 {
+        self.hammer_meso + self.scroll_meso
+    }
+
+    pub fn meso_per_stat(&self) -> Option<f64> {
+        (self.stat_gain > 0.0).then(|| self.meso() / self.stat_gain)
+    }
+
+    pub fn regular_meso_per_stat(&self) -> Option<f64> {
+        (self.stat_gain > 0.0).then(|| self.scroll_meso / self.stat_gain)
+    }
+}
+
+pub fn golden_hammer(context: &UpgradeContext) -> Option<GoldenHammer> {
+    if context.golden_hammer_used.unwrap_or_default() {
+        return None;
+    }
+
+    let success_rate = context.golden_hammer_success_rate.filter(|&rate| rate > 0)?;
+
+    Some(GoldenHammer {
+        success: f64::from(success_rate) / 100.0,
+        price: f64::from(context.golden_hammer_price?),
+    })
+}
+
+pub fn hammer_cost(context: &UpgradeContext) -> f64 {
+    golden_hammer(context).map_or(0.0, |hammer| hammer.expected_cost())
+}
+
+pub fn slot_count(context: &UpgradeContext) -> Option<u32> {
+    Some(context.upgradeable_count? + u32::from(golden_hammer(context).is_some()))
+}
+
+pub fn hammer_slot(context: &UpgradeContext) -> Option<HammerSlot> {
+    let hammer = golden_hammer(context)?;
+    let spell_trace = spell_trace::selected(context)?;
+    let transition = upgrade_cost::slot_transition(context)?;
+    let trace_required = f64::from(spell_trace::selected_trace_required(context)?);
+    let trace_price = f64::from(context.trace_price?);
+
+    let without_hammer = UpgradeContext {
+        golden_hammer_success_rate: None,
+        ..context.clone()
+    };
+
+    Some(HammerSlot {
+        expected_hammers: hammer.expected_count(),
+        hammer_meso: hammer.expected_cost(),
+        scroll_meso: transition.attempts_per_slot() * trace_required * trace_price,
+        stat_gain: transition.successes_per_slot()
+            * f64::from(equipment::stat_gain(context, spell_trace)?),
+        target_meso: strategy::solve(context).map(|strategy| strategy.expected_meso),
+        target_meso_without_hammer: strategy::solve(&without_hammer)
+            .map(|strategy| strategy.expected_meso),
+    })
+}
+
+pub fn stat_gain_text(slot: &HammerSlot) -> String {
+    format!("+{:.2}", slot.stat_gain)
+}
+
+pub fn slot_meso_text(slot: &HammerSlot) -> String {
+    format!(
+        "{} (황금망치 {} + 주문서 {})",
+        upgrade_cost::meso_text(slot.meso()),
+        upgrade_cost::meso_text(slot.hammer_meso),
+        upgrade_cost::meso_text(slot.scroll_meso)
+    )
+}
+
+pub fn meso_per_stat_text(slot: &HammerSlot) -> Option<String> {
+    Some(format!(
+        "{} (기존 칸 {})",
+        upgrade_cost::meso_text(slot.meso_per_stat()?),
+        upgrade_cost::meso_text(slot.regular_meso_per_stat()?)
+    ))
+}
+
+pub fn target_meso_text(slot: &HammerSlot) -> Option<String> {
+    Some(format!(
+        "사용 {} / 미사용 {}",
+        upgrade_cost::meso_text(slot.target_meso?),
+        upgrade_cost::meso_text(slot.target_meso_without_hammer?)
+    ))
+}
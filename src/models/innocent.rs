@@ -0,0 +1,65 @@
+use crate::models::{
+    distribution::Distribution, golden_hammer, spell_trace, stat_outcome,
+    upgrade_context::UpgradeContext, upgrade_cost,
+};
+
+#[derive(Clone, Copy)]
+pub struct InnocentScroll {
+    pub success: f64,
+    pub price: f64,
+}
+
+impl InnocentScroll {
+    pub fn expected_count(&self) -> f64 {
+        1.0 / self.success
+    }
+
+    pub fn expected_cost(&self) -> f64 {
+        self.price * self.expected_count()
+    }
+}
+
+pub struct ResetPlan {
+    pub threshold: usize,
+    pub keep_probability: f64,
+    pub expected_meso: f64,
+    pub expected_innocents: f64,
+    pub final_stat: Distribution,
+}
+
+pub fn innocent_scroll(context: &UpgradeContext) -> Option<InnocentScroll> {
+    let success_rate = context.innocent_success_rate.filter(|&rate| rate > 0)?;
+
+    Some(InnocentScroll {
+        success: f64::from(success_rate) / 100.0,
+        price: f64::from(context.innocent_price?),
+    })
+}
+
+pub fn reset_plan(context: &UpgradeContext) -> Option<ResetPlan> {
+    let innocent = innocent_scroll(context)?;
+    let pass_meso =
+        upgrade_cost::expected_cost(context)?.meso + golden_hammer::hammer_cost(context);
+    let stat = stat_outcome::stat_distribution(context, spell_trace::selected(context)?)?;
+    let threshold = context.target_stat? as usize;
+    let final_stat = stat.at_least(threshold)?;
+    let keep_probability = 1.0 - threshold.checked_sub(1).map_or(0.0, |below| stat.cdf(below));
+    let passes = 1.0 / keep_probability;
+    let resets = passes - 1.0;
+
+    Some(ResetPlan {
+        threshold,
+        keep_probability,
+        expected_meso: passes * pass_meso + resets * innocent.expected_cost(),
+        expected_innocents: resets * innocent.expected_count(),
+        final_stat,
+    })
+}
+
+pub fn threshold_text(plan: &ResetPlan) -> String {
+    format!("모든 횟수 사용 후 +{} 미만이면 이노센트", plan.threshold)
+}
+
+pub fn final_stat_mean_text(plan: &ResetPlan) -> String {
+    format!("+{:.2}", plan.final_stat.mean())
+}
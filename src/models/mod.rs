@@ -1,2 +1,24 @@
+pub mod budget;
+pub mod chaos;
+pub mod distribution;
+pub mod equipment;
+pub mod event;
+pub mod game_data;
+pub mod golden_hammer;
+pub mod innocent;
+pub mod pet_equipment;
+pub mod sensitivity;
+pub mod simulation;
+pub mod slot_transition;
+pub mod spell_trace;
+pub mod starforce;
+pub mod starforce_context;
+pub mod starforce_event;
+pub mod starforce_membership;
+pub mod stat_outcome;
+pub mod strategy;
+pub mod success_rate;
+pub mod target_stat;
 pub mod theme;
 pub mod upgrade_context;
+pub mod upgrade_cost;
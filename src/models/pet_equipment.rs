@@ -0,0 +1,88 @@
+use crate::models::{
+    distribution::Distribution, event, game_data::game_data, slot_transition::SlotTransition,
+    success_rate, upgrade_context::UpgradeContext, upgrade_cost,
+};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct PetEquipment {
+    pub pet_count: u32,
+    pub scrolls: Vec<PetScroll>,
+}
+
+#[derive(Deserialize)]
+pub struct PetScroll {
+    pub id: u32,
+    pub label: String,
+    pub success_rate: u32,
+    pub attack: u32,
+    pub cash: bool,
+}
+
+pub struct PetOutcome {
+    pub pet_count: u32,
+    pub scroll_price: f64,
+    pub attempts: Distribution,
+    pub attack: Distribution,
+}
+
+impl PetOutcome {
+    pub fn expected_attempts(&self) -> f64 {
+        self.attempts.mean()
+    }
+
+    pub fn expected_meso(&self) -> f64 {
+        self.expected_attempts() * self.scroll_price
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    pub fn meso_percentile(&self, percentile: f64) -> f64 {
+        self.attempts.percentile(percentile) as f64 * self.scroll_price
+    }
+
+    pub fn attack_per_pet(&self) -> f64 {
+        self.attack.mean() / f64::from(self.pet_count)
+    }
+}
+
+pub fn pet_scrolls() -> &'static [PetScroll] {
+    &game_data().pet_equipment.scrolls
+}
+
+pub fn find(id: u32) -> Option<&'static PetScroll> {
+    pet_scrolls().iter().find(|scroll| scroll.id == id)
+}
+
+pub fn scroll_price(context: &UpgradeContext, scroll: &PetScroll) -> Option<f64> {
+    let price = f64::from(context.pet_scroll_price?);
+
+    if scroll.cash {
+        Some(price * f64::from(context.cash_exchange_rate?))
+    } else {
+        Some(price)
+    }
+}
+
+pub fn pet_outcome(context: &UpgradeContext) -> Option<PetOutcome> {
+    let scroll = find(context.pet_scroll?)?;
+    let pet_count = game_data().pet_equipment.pet_count;
+    let slots = context.pet_upgradeable_count? * pet_count;
+    let success = success_rate::success_rate(context, scroll.success_rate)?.probability();
+    let transition = SlotTransition::new(success, event::upgrade_salvation(context)?);
+
+    Some(PetOutcome {
+        pet_count,
+        scroll_price: scroll_price(context, scroll)?,
+        attempts: Distribution::geometric(transition.slot_consumed()).repeat(slots),
+        attack: Distribution::bernoulli(transition.successes_per_slot(), scroll.attack as usize)
+            .repeat(slots),
+    })
+}
+
+pub fn attack_text(outcome: &PetOutcome) -> String {
+    format!("+{:.2} (펫 1마리당 +{:.2})", outcome.attack.mean(), outcome.attack_per_pet())
+}
+
+pub fn percentile_text(outcome: &PetOutcome, percentile: f64) -> String {
+    upgrade_cost::meso_text(outcome.meso_percentile(percentile))
+}
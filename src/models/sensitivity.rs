@@ -0,0 +1,79 @@
+use crate::models::{
+    game_data::game_data,
+    strategy,
+    upgrade_context::{
+        UpgradeContext,
+        spec_collection::{self, Spec},
+    },
+    upgrade_cost,
+};
+
+type SkillSetter = fn(&mut UpgradeContext, u32);
+
+pub struct SensitivityRow {
+    pub skill: &'static str,
+    pub level: u32,
+    pub meso: f64,
+    pub savings: f64,
+}
+
+pub fn plan_meso(context: &UpgradeContext) -> Option<f64> {
+    strategy::solve(context)
+        .map(|strategy| strategy.expected_meso)
+        .or_else(|| upgrade_cost::expected_cost(context).map(|cost| cost.meso))
+}
+
+pub fn sensitivity(context: &UpgradeContext) -> Option<Vec<SensitivityRow>> {
+    let current_meso = plan_meso(context)?;
+    let skills: [(&'static Spec, u32, u32, SkillSetter); 3] = [
+        (
+            &spec_collection::HANDICRAFT,
+            context.handicraft?,
+            game_data().guild_skills.handicraft_step,
+            |context, level| {
+                context.handicraft = Some(level);
+            },
+        ),
+        (&spec_collection::ENHANCE_MASTERY, context.enhance_mastery?, 1, |context, level| {
+            context.enhance_mastery = Some(level);
+        }),
+        (&spec_collection::UPGRADE_SALVATION, context.upgrade_salvation?, 1, |context, level| {
+            context.upgrade_salvation = Some(level);
+        }),
+    ];
+
+    skills
+        .into_iter()
+        .flat_map(|(spec, current, step, setter)| {
+            let next = (current / step + 1) * step;
+
+            (next..=spec.max()).step_by(step as usize).map(move |level| {
+                let mut context = context.clone();
+                setter(&mut context, level);
+                (spec.label, level, context)
+            })
+        })
+        .map(|(skill, level, context)| {
+            let meso = plan_meso(&context)?;
+
+            Some(SensitivityRow {
+                skill,
+                level,
+                meso,
+                savings: current_meso - meso,
+            })
+        })
+        .collect()
+}
+
+pub fn level_text(row: &SensitivityRow) -> String {
+    format!("{} {}", row.skill, row.level)
+}
+
+pub fn savings_text(row: &SensitivityRow) -> String {
+    format!(
+        "{} (절약 {})",
+        upgrade_cost::meso_text(row.meso),
+        upgrade_cost::meso_text(row.savings.max(0.0))
+    )
+}
@@ -0,0 +1,203 @@
+use crate::{
+    models::{
+        golden_hammer,
+        slot_transition::SlotOutcome,
+        spell_trace,
+        strategy::{self, Action, Strategy},
+        upgrade_context::UpgradeContext,
+        upgrade_cost,
+    },
+    utils::random::Random,
+};
+
+mod constants {
+    pub const MAX_ATTEMPTS: u64 = 20_000_000;
+}
+
+pub struct Simulation {
+    traces: Vec<u64>,
+    meso: Vec<u64>,
+}
+
+pub enum StrategySimulation {
+    Complete(Simulation),
+    Truncated {
+        runs: u32,
+    },
+}
+
+impl Simulation {
+    fn new(mut traces: Vec<u64>, mut meso: Vec<u64>) -> Self {
+        traces.sort_unstable();
+        meso.sort_unstable();
+
+        Self {
+            traces,
+            meso,
+        }
+    }
+
+    pub fn mean_traces(&self) -> f64 {
+        mean(&self.traces)
+    }
+
+    pub fn mean_meso(&self) -> f64 {
+        mean(&self.meso)
+    }
+
+    pub fn traces_percentile(&self, percentile: f64) -> f64 {
+        sample_percentile(&self.traces, percentile)
+    }
+
+    pub fn meso_percentile(&self, percentile: f64) -> f64 {
+        sample_percentile(&self.meso, percentile)
+    }
+}
+
+pub fn simulate_upgrade(context: &UpgradeContext) -> Option<Simulation> {
+    let runs = context.simulation_runs?;
+    let mut random = Random::new(u64::from(context.simulation_seed?));
+    let transition = upgrade_cost::slot_transition(context)?;
+    let slots = golden_hammer::slot_count(context)?;
+    let trace_required = u64::from(spell_trace::selected_trace_required(context)?);
+    let trace_price = u64::from(context.trace_price?);
+
+    let mut traces = Vec::with_capacity(runs as usize);
+    let mut meso = Vec::with_capacity(runs as usize);
+
+    for _ in 0..runs {
+        let mut attempts = 0;
+        let mut remaining = slots;
+
+        while remaining > 0 {
+            attempts += 1;
+
+            if !matches!(transition.sample(&mut random), SlotOutcome::SlotKept) {
+                remaining -= 1;
+            }
+        }
+
+        traces.push(attempts * trace_required);
+        meso.push(attempts * trace_required * trace_price);
+    }
+
+    Some(Simulation::new(traces, meso))
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub fn simulate_strategy(
+    context: &UpgradeContext,
+    strategy: &Strategy,
+) -> Option<StrategySimulation> {
+    let runs = context.simulation_runs?;
+    let mut random = Random::new(u64::from(context.simulation_seed?));
+    let target = strategy.target();
+
+    let mut traces = Vec::with_capacity(runs as usize);
+    let mut meso = Vec::with_capacity(runs as usize);
+    let mut total_attempts = 0;
+
+    for run in 0..runs {
+        let mut run_traces = 0;
+        let mut run_meso = hammer_meso(strategy, &mut random);
+        let mut state = strategy.start();
+
+        while state.gained < target {
+            total_attempts += 1;
+            if total_attempts > constants::MAX_ATTEMPTS {
+                return Some(StrategySimulation::Truncated {
+                    runs: run,
+                });
+            }
+
+            match strategy.action(state) {
+                Action::Scroll(index) => {
+                    let option = strategy.option(index);
+                    run_traces += u64::from(option.trace_required);
+                    run_meso += option.cost.round() as u64;
+
+                    state = match option.transition.sample(&mut random) {
+                        SlotOutcome::Success => strategy.succeeded_state(state, option),
+                        SlotOutcome::SlotLost => strategy::failed_state(state),
+                        SlotOutcome::SlotKept => state,
+                    };
+                }
+                Action::Restore(_) => {
+                    let clean_slate = strategy.clean_slate()?;
+                    run_meso += clean_slate.price.round() as u64;
+
+                    if random.next_f64() < clean_slate.success {
+                        state.remaining += 1;
+                    }
+                }
+                Action::Reset => {
+                    if let Some(innocent) = strategy.innocent() {
+                        run_meso += innocent.price.round() as u64;
+
+                        if random.next_f64() >= innocent.success {
+                            continue;
+                        }
+                    } else {
+                        run_meso += strategy.item_price().round() as u64;
+                    }
+
+                    run_meso += hammer_meso(strategy, &mut random);
+                    state = strategy.start();
+                }
+            }
+        }
+
+        traces.push(run_traces);
+        meso.push(run_meso);
+    }
+
+    Some(StrategySimulation::Complete(Simulation::new(traces, meso)))
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn hammer_meso(strategy: &Strategy, random: &mut Random) -> u64 {
+    let Some(hammer) = strategy.hammer() else {
+        return 0;
+    };
+    let mut meso = hammer.price.round() as u64;
+
+    while random.next_f64() >= hammer.success {
+        meso += hammer.price.round() as u64;
+    }
+
+    meso
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn mean(samples: &[u64]) -> f64 {
+    if samples.is_empty() {
+        return 0.0;
+    }
+
+    samples.iter().map(|&sample| sample as f64).sum::<f64>() / samples.len() as f64
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss, clippy::cast_sign_loss)]
+fn sample_percentile(samples: &[u64], percentile: f64) -> f64 {
+    let index = ((samples.len() as f64 * percentile).ceil() as usize).saturating_sub(1);
+
+    samples.get(index).map_or(0.0, |&sample| sample as f64)
+}
+
+pub fn comparison_text(simulated: &str, analytic: &str) -> String {
+    format!("{simulated} (해석값 {analytic})")
+}
+
+pub fn strategy_simulation_text(simulation: &StrategySimulation, analytic: &str) -> String {
+    match simulation {
+        StrategySimulation::Complete(simulation) => {
+            comparison_text(&upgrade_cost::meso_text(simulation.mean_meso()), analytic)
+        }
+        StrategySimulation::Truncated {
+            runs,
+        } => format!(
+            "총 시도 {}회 상한에 도달해 {runs}회차에서 중단됨 (해석값 {analytic})",
+            constants::MAX_ATTEMPTS
+        ),
+    }
+}
@@ -0,0 +1,48 @@
+use crate::utils::random::Random;
+
+pub enum SlotOutcome {
+    Success,
+    SlotLost,
+    SlotKept,
+}
+
+#[derive(Clone, Copy)]
+pub struct SlotTransition {
+    pub success: f64,
+    pub slot_lost: f64,
+    pub slot_kept: f64,
+}
+
+impl SlotTransition {
+    pub fn new(success: f64, salvation: f64) -> Self {
+        Self {
+            success,
+            slot_lost: (1.0 - success) * (1.0 - salvation),
+            slot_kept: (1.0 - success) * salvation,
+        }
+    }
+
+    pub fn slot_consumed(&self) -> f64 {
+        1.0 - self.slot_kept
+    }
+
+    pub fn attempts_per_slot(&self) -> f64 {
+        1.0 / self.slot_consumed()
+    }
+
+    pub fn successes_per_slot(&self) -> f64 {
+        self.success / self.slot_consumed()
+    }
+
+    pub fn sample(&self, random: &mut Random) -> SlotOutcome {
+        let roll = random.next_f64();
+
+        if roll < self.success {
+            SlotOutcome::Success
+        } else if roll < self.success + self.slot_lost {
+            SlotOutcome::SlotLost
+        } else {
+            SlotOutcome::SlotKept
+        }
+    }
+}
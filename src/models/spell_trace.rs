@@ -0,0 +1,109 @@
+use crate::models::{
+    equipment::{self, LevelBracket},
+    event,
+    game_data::game_data,
+    upgrade_context::UpgradeContext,
+};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct SpellTrace {
+    pub label: String,
+    pub success_rate: u32,
+    pub trace_required: u32,
+    pub stat_gain: u32,
+}
+
+pub enum TraceSource {
+    Manual,
+    Table,
+    Default,
+}
+
+pub struct TraceRequirement {
+    pub trace_required: u32,
+    pub source: TraceSource,
+}
+
+#[derive(Deserialize)]
+pub struct TraceCost {
+    category: u32,
+    bracket: LevelBracket,
+    trace_required: Vec<u32>,
+}
+
+impl TraceCost {
+    pub fn tiers(&self) -> usize {
+        self.trace_required.len()
+    }
+}
+
+pub fn spell_traces() -> &'static [SpellTrace] {
+    &game_data().spell_traces
+}
+
+pub fn tier(spell_trace: &SpellTrace) -> Option<usize> {
+    spell_traces().iter().position(|candidate| candidate.success_rate == spell_trace.success_rate)
+}
+
+pub fn find(success_rate: u32) -> Option<&'static SpellTrace> {
+    spell_traces().iter().find(|spell_trace| spell_trace.success_rate == success_rate)
+}
+
+pub fn selected(context: &UpgradeContext) -> Option<&'static SpellTrace> {
+    find(context.spell_trace?).filter(|spell_trace| equipment::allows(context, spell_trace))
+}
+
+pub fn trace_required(context: &UpgradeContext, spell_trace: &SpellTrace) -> u32 {
+    event::discounted_traces(context, trace_requirement(context, spell_trace).trace_required)
+}
+
+pub fn selected_trace_required(context: &UpgradeContext) -> Option<u32> {
+    Some(trace_required(context, selected(context)?))
+}
+
+pub fn trace_requirement(context: &UpgradeContext, spell_trace: &SpellTrace) -> TraceRequirement {
+    let manual =
+        context.trace_required.filter(|_| context.spell_trace == Some(spell_trace.success_rate));
+
+    if let Some(trace_required) = manual {
+        return TraceRequirement {
+            trace_required,
+            source: TraceSource::Manual,
+        };
+    }
+
+    match table_trace_required(context, spell_trace) {
+        Some(trace_required) => TraceRequirement {
+            trace_required,
+            source: TraceSource::Table,
+        },
+        None => TraceRequirement {
+            trace_required: spell_trace.trace_required,
+            source: TraceSource::Default,
+        },
+    }
+}
+
+fn table_trace_required(context: &UpgradeContext, spell_trace: &SpellTrace) -> Option<u32> {
+    let (category, bracket) = equipment::context_bracket(context)?;
+    let tier = tier(spell_trace)?;
+
+    game_data()
+        .trace_costs
+        .iter()
+        .find(|cost| cost.category == category && cost.bracket == bracket)?
+        .trace_required
+        .get(tier)
+        .copied()
+}
+
+pub fn trace_requirement_text(requirement: &TraceRequirement) -> String {
+    let source = match requirement.source {
+        TraceSource::Manual => "직접 입력",
+        TraceSource::Table => "자동 계산",
+        TraceSource::Default => "기본값",
+    };
+
+    format!("{}개 ({source})", requirement.trace_required)
+}
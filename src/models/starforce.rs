@@ -0,0 +1,664 @@
+use crate::models::{
+    distribution::Distribution,
+    game_data::game_data,
+    starforce_context::StarforceContext,
+    starforce_event::{self, StarforceEvent},
+    starforce_membership::{self, MvpGrade, StarforceMembership},
+};
+use serde::Deserialize;
+
+mod constants {
+    pub const DISTRIBUTION_BUCKETS: usize = 2000;
+    pub const DISTRIBUTION_SPAN: f64 = 12.0;
+    pub const MESO_PER_COST_UNIT: f64 = 10_000.0;
+    pub const SPARE_RECOVERY: u32 = 0;
+    pub const REBUILD_RECOVERY: u32 = 1;
+    pub const SPARE_LABEL: &str = "스페어";
+    pub const REBUILD_LABEL: &str = "흔적 복구 + 재작";
+    pub const UNGUARDED_LABEL: &str = "미적용";
+    pub const ON_LABEL: &str = "O";
+    pub const OFF_LABEL: &str = "X";
+}
+
+#[derive(Deserialize)]
+pub struct StarforceData {
+    pub destroyed_star: u32,
+    pub cost_base: f64,
+    pub cost_unit: f64,
+    pub star_limits: Vec<StarLimit>,
+    pub star_catch_multiplier: f64,
+    pub safeguard: Safeguard,
+    pub events: Vec<StarforceEvent>,
+    pub membership: StarforceMembership,
+    pub stars: Vec<StarRule>,
+}
+
+#[derive(Deserialize)]
+pub struct StarLimit {
+    pub max_level: u32,
+    pub max_star: u32,
+}
+
+#[derive(Deserialize)]
+pub struct Safeguard {
+    pub min_star: u32,
+    pub max_star: u32,
+    pub surcharge: f64,
+}
+
+impl Safeguard {
+    pub fn allows(&self, star: u32) -> bool {
+        (self.min_star..=self.max_star).contains(&star)
+    }
+
+    pub fn stars(&self) -> impl Iterator<Item = u32> {
+        self.min_star..=self.max_star
+    }
+}
+
+#[derive(Deserialize)]
+pub struct StarRule {
+    pub success: f64,
+    pub destroy: f64,
+    pub cost_divisor: f64,
+    pub cost_exponent: f64,
+}
+
+#[derive(Clone, Copy)]
+pub struct StarStep {
+    pub success: f64,
+    pub destroy: f64,
+    pub cost: f64,
+}
+
+impl StarStep {
+    fn leave(&self) -> f64 {
+        self.success + self.destroy
+    }
+
+    fn fail(&self) -> f64 {
+        1.0 - self.leave()
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub struct StarforceInputs {
+    pub equipment_level: u32,
+    pub mvp_grade: Option<u32>,
+    pub pc_room: bool,
+    pub recovery: f64,
+}
+
+#[derive(Clone, Copy)]
+pub struct StarforceOptions {
+    pub star_catch: bool,
+    pub safeguard_stars: u32,
+    pub event: Option<&'static StarforceEvent>,
+    pub mvp_grade: Option<&'static MvpGrade>,
+    pub pc_room: bool,
+}
+
+impl StarforceOptions {
+    pub fn from_context(context: &StarforceContext, inputs: StarforceInputs) -> Self {
+        Self {
+            star_catch: context.star_catch.unwrap_or_default(),
+            safeguard_stars: context.safeguard_stars.unwrap_or_default(),
+            event: context.starforce_event.and_then(starforce_event::find),
+            mvp_grade: inputs.mvp_grade.and_then(starforce_membership::find),
+            pc_room: inputs.pc_room,
+        }
+    }
+
+    fn cost_multiplier(self, star: u32) -> f64 {
+        let event_multiplier = self.event.map_or(1.0, StarforceEvent::cost_multiplier);
+        let membership_multiplier =
+            starforce_membership::membership().cost_multiplier(self.mvp_grade, self.pc_room, star);
+
+        event_multiplier * membership_multiplier
+    }
+
+    fn guaranteed(self, star: u32) -> bool {
+        self.event.is_some_and(|event| event.guarantees(star))
+    }
+
+    fn safeguarded(self, star: u32) -> bool {
+        is_marked(self.safeguard_stars, star)
+            && game_data().starforce.safeguard.allows(star)
+            && !self.guaranteed(star)
+    }
+}
+
+pub struct StarforcePlan {
+    steps: Vec<StarStep>,
+    current: usize,
+    target: usize,
+    destroyed: usize,
+    recovery: f64,
+}
+
+pub struct StarforceCost {
+    pub attempts: f64,
+    pub destructions: f64,
+    pub recovery: f64,
+    pub meso: f64,
+}
+
+pub struct StarforceDistribution {
+    unit: f64,
+    meso: Distribution,
+    no_destruction: f64,
+}
+
+pub struct OptionOutcome {
+    pub star_catch: bool,
+    pub safeguard: bool,
+    pub cost: StarforceCost,
+}
+
+pub struct SafeguardPolicy {
+    pub stars: Vec<u32>,
+    pub cost: StarforceCost,
+}
+
+impl StarforceDistribution {
+    #[allow(clippy::cast_precision_loss)]
+    pub fn meso_percentile(&self, percentile: f64) -> f64 {
+        self.meso.percentile(percentile) as f64 * self.unit
+    }
+
+    pub fn no_destruction(&self) -> f64 {
+        self.no_destruction
+    }
+}
+
+struct Absorption {
+    attempts: Vec<f64>,
+    meso: Vec<f64>,
+    destroy_chance: Vec<f64>,
+}
+
+impl StarforcePlan {
+    fn absorption(&self) -> Absorption {
+        let mut absorption = Absorption {
+            attempts: vec![0.0; self.target + 1],
+            meso: vec![0.0; self.target + 1],
+            destroy_chance: vec![0.0; self.target + 1],
+        };
+
+        for star in (0..self.target).rev() {
+            let step = self.steps[star];
+            let next = star + 1;
+
+            absorption.attempts[star] =
+                (1.0 + step.success * absorption.attempts[next]) / step.leave();
+            absorption.meso[star] =
+                (step.cost + step.destroy * self.recovery + step.success * absorption.meso[next])
+                    / step.leave();
+            absorption.destroy_chance[star] =
+                (step.destroy + step.success * absorption.destroy_chance[next]) / step.leave();
+        }
+
+        absorption
+    }
+
+    pub fn expected_cost(&self) -> StarforceCost {
+        let absorption = self.absorption();
+        let restart = |values: &[f64]| {
+            if self.destroyed < self.target {
+                values[self.destroyed] / (1.0 - absorption.destroy_chance[self.destroyed])
+            } else {
+                0.0
+            }
+        };
+        let total = |values: &[f64]| {
+            values[self.current] + absorption.destroy_chance[self.current] * restart(values)
+        };
+
+        let destructions = total(&absorption.destroy_chance);
+
+        StarforceCost {
+            attempts: total(&absorption.attempts),
+            destructions,
+            recovery: destructions * self.recovery,
+            meso: total(&absorption.meso),
+        }
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn cost_distribution(&self) -> StarforceDistribution {
+        let expected = self.expected_cost();
+        let unit =
+            expected.meso * constants::DISTRIBUTION_SPAN / constants::DISTRIBUTION_BUCKETS as f64;
+        let (reached, destroyed) = self.run(self.current, unit);
+        let destroyed = shifted(&destroyed, self.recovery / unit);
+
+        let meso = if self.destroyed < self.target {
+            let (restart_reached, restart_destroyed) = self.run(self.destroyed, unit);
+            let restart_destroyed = shifted(&restart_destroyed, self.recovery / unit);
+            let restarted = renewal(&restart_reached, &restart_destroyed);
+
+            add(&reached, &truncated_convolve(&destroyed, &restarted))
+        } else {
+            add(&reached, &destroyed)
+        };
+
+        StarforceDistribution {
+            unit,
+            meso: Distribution::new(meso),
+            no_destruction: 1.0 - self.absorption().destroy_chance[self.current],
+        }
+    }
+
+    fn run(&self, from: usize, unit: f64) -> (Vec<f64>, Vec<f64>) {
+        let mut arrival = vec![0.0; constants::DISTRIBUTION_BUCKETS];
+        let mut destroyed = vec![0.0; constants::DISTRIBUTION_BUCKETS];
+        arrival[0] = 1.0;
+
+        for step in &self.steps[from..self.target] {
+            let leaving = attempt_filter(&arrival, *step, unit);
+
+            for (bucket, mass) in leaving.into_iter().enumerate() {
+                arrival[bucket] = mass * step.success / step.leave();
+                destroyed[bucket] += mass * step.destroy / step.leave();
+            }
+        }
+
+        (arrival, destroyed)
+    }
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn attempt_filter(input: &[f64], step: StarStep, unit: f64) -> Vec<f64> {
+    let exact = step.cost / unit;
+    let whole = exact.floor();
+    let fraction = exact - whole;
+    let whole = whole as usize;
+    let mut output = vec![0.0; input.len()];
+
+    for bucket in 0..input.len() {
+        let mut value = 0.0;
+        let mut looped = 0.0;
+
+        for (shift, weight) in [(whole, 1.0 - fraction), (whole + 1, fraction)] {
+            let Some(source) = bucket.checked_sub(shift) else {
+                continue;
+            };
+
+            value += weight * step.leave() * input[source];
+
+            if source == bucket {
+                looped += weight * step.fail();
+            } else {
+                value += weight * step.fail() * output[source];
+            }
+        }
+
+        output[bucket] = value / (1.0 - looped);
+    }
+
+    output
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn shifted(values: &[f64], buckets: f64) -> Vec<f64> {
+    let whole = buckets.floor();
+    let fraction = buckets - whole;
+    let whole = whole as usize;
+    let mut output = vec![0.0; values.len()];
+
+    for (bucket, mass) in values.iter().enumerate() {
+        for (shift, weight) in [(whole, 1.0 - fraction), (whole + 1, fraction)] {
+            if let Some(slot) = output.get_mut(bucket + shift) {
+                *slot += weight * mass;
+            }
+        }
+    }
+
+    output
+}
+
+fn renewal(reached: &[f64], destroyed: &[f64]) -> Vec<f64> {
+    let mut total = vec![0.0; reached.len()];
+
+    for bucket in 0..reached.len() {
+        let repeated: f64 =
+            (1..=bucket).map(|shift| destroyed[shift] * total[bucket - shift]).sum();
+
+        total[bucket] = (reached[bucket] + repeated) / (1.0 - destroyed[0]);
+    }
+
+    total
+}
+
+fn truncated_convolve(left: &[f64], right: &[f64]) -> Vec<f64> {
+    let mut output = vec![0.0; left.len()];
+
+    for (i, p) in left.iter().enumerate().filter(|(_, p)| **p > 0.0) {
+        for (j, q) in right.iter().take(left.len() - i).enumerate() {
+            output[i + j] += p * q;
+        }
+    }
+
+    output
+}
+
+fn add(left: &[f64], right: &[f64]) -> Vec<f64> {
+    left.iter().zip(right).map(|(p, q)| p + q).collect()
+}
+
+pub fn max_star(equipment_level: u32) -> u32 {
+    let data = &game_data().starforce;
+    let table_max = u32::try_from(data.stars.len()).unwrap_or(u32::MAX);
+
+    data.star_limits
+        .iter()
+        .find(|limit| equipment_level <= limit.max_level)
+        .map_or(table_max, |limit| limit.max_star.min(table_max))
+}
+
+pub fn safeguard_stars() -> impl Iterator<Item = u32> {
+    game_data().starforce.safeguard.stars()
+}
+
+pub fn is_marked(stars: u32, star: u32) -> bool {
+    1u32.checked_shl(star).is_some_and(|bit| stars & bit != 0)
+}
+
+pub fn mark(stars: u32, star: u32) -> u32 {
+    stars | 1u32.checked_shl(star).unwrap_or_default()
+}
+
+pub fn unmark(stars: u32, star: u32) -> u32 {
+    stars & !1u32.checked_shl(star).unwrap_or_default()
+}
+
+pub fn star_cost(equipment_level: u32, star: u32, rule: &StarRule) -> f64 {
+    let data = &game_data().starforce;
+    let level = f64::from(equipment_level);
+    let cost = data.cost_base
+        + level.powi(3) * f64::from(star + 1).powf(rule.cost_exponent) / rule.cost_divisor;
+
+    (cost / data.cost_unit).round() * data.cost_unit
+}
+
+pub fn star_step(
+    equipment_level: u32,
+    star: u32,
+    rule: &StarRule,
+    options: StarforceOptions,
+) -> StarStep {
+    let data = &game_data().starforce;
+    let base_cost = star_cost(equipment_level, star, rule);
+    let cost = base_cost * options.cost_multiplier(star);
+    let destroy = rule.destroy * options.event.map_or(1.0, |event| event.destroy_multiplier(star));
+    let (success, destroy) = if options.guaranteed(star) {
+        (1.0, 0.0)
+    } else if options.star_catch && rule.success < 1.0 {
+        let success = (rule.success * data.star_catch_multiplier).min(1.0);
+        (success, destroy * (1.0 - success) / (1.0 - rule.success))
+    } else {
+        (rule.success, destroy)
+    };
+
+    if options.safeguarded(star) {
+        StarStep {
+            success,
+            destroy: 0.0,
+            cost: cost + base_cost * data.safeguard.surcharge,
+        }
+    } else {
+        StarStep {
+            success,
+            destroy,
+            cost,
+        }
+    }
+}
+
+pub fn starforce_plan(
+    context: &StarforceContext,
+    inputs: StarforceInputs,
+) -> Option<StarforcePlan> {
+    plan_with(context, StarforceOptions::from_context(context, inputs), inputs)
+}
+
+pub fn plan_with(
+    context: &StarforceContext,
+    options: StarforceOptions,
+    inputs: StarforceInputs,
+) -> Option<StarforcePlan> {
+    let equipment_level = inputs.equipment_level;
+    let current = context.current_star?;
+    let target = context.target_star?;
+
+    if current >= target || target > max_star(equipment_level) {
+        return None;
+    }
+
+    let data = &game_data().starforce;
+    let steps = (0..target)
+        .zip(&data.stars)
+        .map(|(star, rule)| star_step(equipment_level, star, rule, options))
+        .collect();
+
+    Some(StarforcePlan {
+        steps,
+        current: current as usize,
+        target: target as usize,
+        destroyed: data.destroyed_star as usize,
+        recovery: inputs.recovery,
+    })
+}
+
+pub fn recovery_meso(context: &StarforceContext, rebuild_meso: Option<f64>) -> Option<f64> {
+    if context.recovery_method.unwrap_or_default() == constants::SPARE_RECOVERY {
+        Some(cost_meso(context.spare_price.unwrap_or_default()))
+    } else {
+        Some(cost_meso(context.restoration_cost.unwrap_or_default()) + rebuild_meso?)
+    }
+}
+
+pub fn recovery_methods() -> [(u32, &'static str); 2] {
+    [
+        (constants::SPARE_RECOVERY, constants::SPARE_LABEL),
+        (constants::REBUILD_RECOVERY, constants::REBUILD_LABEL),
+    ]
+}
+
+pub fn expected_cost(context: &StarforceContext, inputs: StarforceInputs) -> Option<StarforceCost> {
+    starforce_plan(context, inputs).map(|plan| plan.expected_cost())
+}
+
+pub fn cost_distribution(
+    context: &StarforceContext,
+    inputs: StarforceInputs,
+) -> Option<StarforceDistribution> {
+    starforce_plan(context, inputs).map(|plan| plan.cost_distribution())
+}
+
+pub fn option_comparison(
+    context: &StarforceContext,
+    inputs: StarforceInputs,
+) -> Option<Vec<OptionOutcome>> {
+    let options = StarforceOptions::from_context(context, inputs);
+    let star_catch = [false, true];
+    let all_stars = safeguard_stars().fold(0, mark);
+
+    [false, true]
+        .into_iter()
+        .flat_map(|safeguard| star_catch.map(|star_catch| (star_catch, safeguard)))
+        .map(|(star_catch, safeguard)| {
+            let options = StarforceOptions {
+                star_catch,
+                safeguard_stars: if safeguard {
+                    all_stars
+                } else {
+                    0
+                },
+                ..options
+            };
+
+            Some(OptionOutcome {
+                star_catch,
+                safeguard,
+                cost: plan_with(context, options, inputs)?.expected_cost(),
+            })
+        })
+        .collect()
+}
+
+pub fn safeguard_policy(
+    context: &StarforceContext,
+    inputs: StarforceInputs,
+) -> Option<SafeguardPolicy> {
+    let target = context.target_star?;
+    let options = StarforceOptions::from_context(context, inputs);
+    let candidates: Vec<u32> = safeguard_stars().filter(|star| *star < target).collect();
+
+    (0..1u32 << candidates.len())
+        .filter_map(|subset| {
+            let stars: Vec<u32> = candidates
+                .iter()
+                .enumerate()
+                .filter(|(index, _)| subset >> index & 1 != 0)
+                .map(|(_, star)| *star)
+                .collect();
+            let options = StarforceOptions {
+                safeguard_stars: stars.iter().copied().fold(0, mark),
+                ..options
+            };
+
+            Some(SafeguardPolicy {
+                cost: plan_with(context, options, inputs)?.expected_cost(),
+                stars,
+            })
+        })
+        .min_by(|a, b| a.cost.meso.total_cmp(&b.cost.meso))
+}
+
+pub fn max_star_text(equipment_level: u32) -> String {
+    format!("최대 {}성", max_star(equipment_level))
+}
+
+pub fn cost_meso(cost: u32) -> f64 {
+    f64::from(cost) * constants::MESO_PER_COST_UNIT
+}
+
+pub fn safeguard_text(policy: &SafeguardPolicy) -> String {
+    if policy.stars.is_empty() {
+        return constants::UNGUARDED_LABEL.to_owned();
+    }
+
+    let stars: Vec<String> = policy.stars.iter().map(u32::to_string).collect();
+
+    format!("{}성", stars.join(", "))
+}
+
+pub fn safeguard_label(star: u32) -> String {
+    format!("{star}성 파괴방지")
+}
+
+pub fn on_off_label(on: bool) -> &'static str {
+    if on {
+        constants::ON_LABEL
+    } else {
+        constants::OFF_LABEL
+    }
+}
+
+pub fn option_label(outcome: &OptionOutcome) -> String {
+    format!(
+        "스타캐치 {} · 파괴방지 {}",
+        on_off_label(outcome.star_catch),
+        on_off_label(outcome.safeguard)
+    )
+}
+
+pub fn destructions_text(destructions: f64) -> String {
+    format!("{destructions:.2}회")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        StarforceCost, StarforceDistribution, StarforceInputs, cost_distribution, expected_cost,
+    };
+    use crate::models::starforce_context::StarforceContext;
+
+    const INPUTS: StarforceInputs = StarforceInputs {
+        equipment_level: 150,
+        mvp_grade: None,
+        pc_room: false,
+        recovery: 1e9,
+    };
+
+    fn context(current_star: u32, target_star: u32) -> StarforceContext {
+        StarforceContext {
+            current_star: Some(current_star),
+            target_star: Some(target_star),
+            ..StarforceContext::default()
+        }
+    }
+
+    fn cost(context: &StarforceContext) -> StarforceCost {
+        expected_cost(context, INPUTS).unwrap()
+    }
+
+    fn distribution(context: &StarforceContext) -> StarforceDistribution {
+        cost_distribution(context, INPUTS).unwrap()
+    }
+
+    #[test]
+    fn expected_cost_without_destruction_matches_hand_calculation() {
+        let cost = cost(&context(0, 2));
+
+        assert!((cost.attempts - (1.0 / 0.95 + 1.0 / 0.9)).abs() < 1e-9);
+        assert!((cost.meso - (94_800.0 / 0.95 + 188_500.0 / 0.9)).abs() < 1e-6);
+        assert!(cost.destructions.abs() < 1e-12);
+    }
+
+    #[test]
+    fn destruction_restarts_from_destroyed_star() {
+        let climb = cost(&context(12, 15));
+        let cost = cost(&context(15, 16));
+        let destroy_chance = 0.021 / (0.3 + 0.021);
+        let per_try = 1.0 / (0.3 + 0.021);
+        let destructions = destroy_chance / (1.0 - destroy_chance);
+
+        assert!((cost.destructions - destructions).abs() < 1e-9);
+        assert!(
+            (cost.attempts - (per_try + destructions * (climb.attempts + per_try))).abs() < 1e-9
+        );
+    }
+
+    #[test]
+    fn distribution_mean_matches_expected_cost() {
+        for (current_star, target_star) in [(0, 10), (15, 17)] {
+            let context = context(current_star, target_star);
+            let expected = cost(&context);
+            let distribution = distribution(&context);
+            let mean = distribution.meso.mean() * distribution.unit;
+
+            assert!((mean - expected.meso).abs() / expected.meso < 0.01);
+        }
+    }
+
+    #[test]
+    fn no_destruction_is_first_pass_survival() {
+        let safe = distribution(&context(0, 10));
+        let risky = distribution(&context(15, 16));
+
+        assert!((safe.no_destruction() - 1.0).abs() < 1e-12);
+        assert!((risky.no_destruction() - 0.3 / (0.3 + 0.021)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn safeguard_removes_destruction() {
+        let context = StarforceContext {
+            safeguard_stars: Some(super::mark(super::mark(0, 15), 16)),
+            ..context(15, 17)
+        };
+
+        assert!(cost(&context).destructions.abs() < 1e-12);
+    }
+}
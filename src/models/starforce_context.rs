@@ -0,0 +1,71 @@
+use crate::models::{starforce, upgrade_cost};
+use serde::{Deserialize, Serialize};
+
+pub mod spec_collection {
+    pub use crate::models::upgrade_context::spec_collection::Spec;
+
+    pub const CURRENT_STAR: Spec = Spec {
+        label: "현재 스타포스",
+        placeholder: None,
+        range: |ranges| ranges.current_star,
+    };
+
+    pub const TARGET_STAR: Spec = Spec {
+        label: "목표 스타포스",
+        placeholder: None,
+        range: |ranges| ranges.target_star,
+    };
+
+    pub const STAR_CATCH: Spec = Spec {
+        label: "스타캐치",
+        placeholder: Some("선택"),
+        range: |ranges| ranges.star_catch,
+    };
+
+    pub const STARFORCE_EVENT: Spec = Spec {
+        label: "스타포스 이벤트",
+        placeholder: Some("선택"),
+        range: |ranges| ranges.starforce_event,
+    };
+
+    pub const RECOVERY_METHOD: Spec = Spec {
+        label: "파괴 시 복구 방법",
+        placeholder: Some("선택"),
+        range: |ranges| ranges.recovery_method,
+    };
+
+    pub const SPARE_PRICE: Spec = Spec {
+        label: "스페어 가격 (만 메소)",
+        placeholder: Some("0"),
+        range: |ranges| ranges.spare_price,
+    };
+
+    pub const RESTORATION_COST: Spec = Spec {
+        label: "주문의 흔적 복구 비용 (만 메소)",
+        placeholder: Some("0"),
+        range: |ranges| ranges.restoration_cost,
+    };
+}
+
+#[derive(Clone, Default, Deserialize, Serialize)]
+pub struct StarforceContext {
+    pub current_star: Option<u32>,
+    pub target_star: Option<u32>,
+    pub star_catch: Option<bool>,
+    pub safeguard_stars: Option<u32>,
+    pub starforce_event: Option<u32>,
+    pub recovery_method: Option<u32>,
+    pub spare_price: Option<u32>,
+    pub restoration_cost: Option<u32>,
+}
+
+pub fn spare_price_tooltip(spare_price: u32) -> String {
+    upgrade_cost::meso_text(starforce::cost_meso(spare_price))
+}
+
+pub fn restoration_cost_tooltip(restoration_cost: u32) -> String {
+    format!(
+        "{} + 주문서 재작 비용",
+        upgrade_cost::meso_text(starforce::cost_meso(restoration_cost))
+    )
+}
@@ -0,0 +1,69 @@
+use crate::models::{
+    game_data::game_data,
+    starforce::{self, StarforceCost, StarforceInputs, StarforceOptions},
+    starforce_context::StarforceContext,
+};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct StarforceEvent {
+    pub id: u32,
+    pub label: String,
+    pub discount: f64,
+    pub guaranteed_stars: Vec<u32>,
+    pub destroy_reduction: f64,
+    pub destroy_reduction_max_star: u32,
+}
+
+impl StarforceEvent {
+    pub fn guarantees(&self, star: u32) -> bool {
+        self.guaranteed_stars.contains(&star)
+    }
+
+    pub fn cost_multiplier(&self) -> f64 {
+        1.0 - self.discount
+    }
+
+    pub fn destroy_multiplier(&self, star: u32) -> f64 {
+        if star <= self.destroy_reduction_max_star {
+            1.0 - self.destroy_reduction
+        } else {
+            1.0
+        }
+    }
+}
+
+pub struct EventOutcome {
+    pub label: &'static str,
+    pub cost: StarforceCost,
+}
+
+pub fn starforce_events() -> &'static [StarforceEvent] {
+    &game_data().starforce.events
+}
+
+pub fn find(id: u32) -> Option<&'static StarforceEvent> {
+    starforce_events().iter().find(|event| event.id == id)
+}
+
+pub fn event_comparison(
+    context: &StarforceContext,
+    inputs: StarforceInputs,
+) -> Option<Vec<EventOutcome>> {
+    let options = StarforceOptions::from_context(context, inputs);
+
+    starforce_events()
+        .iter()
+        .map(|event| {
+            let options = StarforceOptions {
+                event: Some(event),
+                ..options
+            };
+
+            Some(EventOutcome {
+                label: event.label.as_str(),
+                cost: starforce::plan_with(context, options, inputs)?.expected_cost(),
+            })
+        })
+        .collect()
+}
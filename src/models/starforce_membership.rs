@@ -0,0 +1,84 @@
+use crate::models::{
+    game_data::game_data,
+    starforce::{self, StarforceCost, StarforceInputs, StarforceOptions},
+    starforce_context::StarforceContext,
+};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct StarforceMembership {
+    pub max_star: u32,
+    pub pc_room_discount: f64,
+    pub mvp_grades: Vec<MvpGrade>,
+}
+
+impl StarforceMembership {
+    pub fn cost_multiplier(&self, mvp_grade: Option<&MvpGrade>, pc_room: bool, star: u32) -> f64 {
+        if star > self.max_star {
+            return 1.0;
+        }
+
+        let mvp_discount = mvp_grade.map_or(0.0, |grade| grade.discount);
+        let pc_room_discount = if pc_room {
+            self.pc_room_discount
+        } else {
+            0.0
+        };
+
+        1.0 - mvp_discount - pc_room_discount
+    }
+}
+
+#[derive(Deserialize)]
+pub struct MvpGrade {
+    pub id: u32,
+    pub label: String,
+    pub discount: f64,
+}
+
+pub struct MembershipOutcome {
+    pub mvp_grade: &'static str,
+    pub pc_room: bool,
+    pub cost: StarforceCost,
+}
+
+pub fn membership() -> &'static StarforceMembership {
+    &game_data().starforce.membership
+}
+
+pub fn mvp_grades() -> &'static [MvpGrade] {
+    &membership().mvp_grades
+}
+
+pub fn find(id: u32) -> Option<&'static MvpGrade> {
+    mvp_grades().iter().find(|grade| grade.id == id)
+}
+
+pub fn membership_comparison(
+    context: &StarforceContext,
+    inputs: StarforceInputs,
+) -> Option<Vec<MembershipOutcome>> {
+    let options = StarforceOptions::from_context(context, inputs);
+
+    mvp_grades()
+        .iter()
+        .flat_map(|grade| [false, true].map(|pc_room| (grade, pc_room)))
+        .map(|(grade, pc_room)| {
+            let options = StarforceOptions {
+                mvp_grade: Some(grade),
+                pc_room,
+                ..options
+            };
+
+            Some(MembershipOutcome {
+                mvp_grade: grade.label.as_str(),
+                pc_room,
+                cost: starforce::plan_with(context, options, inputs)?.expected_cost(),
+            })
+        })
+        .collect()
+}
+
+pub fn membership_label(outcome: &MembershipOutcome) -> String {
+    format!("{} · PC방 {}", outcome.mvp_grade, starforce::on_off_label(outcome.pc_room))
+}
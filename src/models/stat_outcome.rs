@@ -0,0 +1,64 @@
+use crate::models::{
+    distribution::Distribution, equipment, golden_hammer, spell_trace::SpellTrace,
+    upgrade_context::UpgradeContext, upgrade_cost,
+};
+
+mod constants {
+    pub const MEDIAN: f64 = 0.5;
+}
+
+pub struct StatOutcome {
+    pub gain: u32,
+    pub distribution: Distribution,
+    pub target_probability: Option<f64>,
+}
+
+pub fn stat_distribution(
+    context: &UpgradeContext,
+    spell_trace: &SpellTrace,
+) -> Option<Distribution> {
+    let transition = upgrade_cost::tier_transition(context, spell_trace)?;
+    let gain = equipment::stat_gain(context, spell_trace)? as usize;
+    let bonus = equipment::success_bonus(context);
+    let successes = Distribution::bernoulli(transition.successes_per_slot(), 1)
+        .repeat(golden_hammer::slot_count(context)?);
+
+    Some(successes.map(|successes| successes * gain + equipment::bonus_gain(bonus, successes)))
+}
+
+pub fn stat_outcome(context: &UpgradeContext, spell_trace: &SpellTrace) -> Option<StatOutcome> {
+    if !equipment::allows(context, spell_trace) {
+        return None;
+    }
+
+    let distribution = stat_distribution(context, spell_trace)?;
+    let target_probability = context.target_stat.map(|target| {
+        1.0 - (target as usize).checked_sub(1).map_or(0.0, |below| distribution.cdf(below))
+    });
+
+    Some(StatOutcome {
+        gain: equipment::stat_gain(context, spell_trace)?,
+        distribution,
+        target_probability,
+    })
+}
+
+pub fn outcome_text(outcome: &StatOutcome) -> String {
+    let summary = format!(
+        "1회 +{} / 기대 +{:.2} / 중앙값 +{}",
+        outcome.gain,
+        outcome.distribution.mean(),
+        outcome.distribution.percentile(constants::MEDIAN)
+    );
+
+    match outcome.target_probability {
+        Some(probability) => {
+            format!("{summary} / 목표 달성 {}", upgrade_cost::probability_text(probability))
+        }
+        None => summary,
+    }
+}
+
+pub fn stat_text(stat: usize, probability: f64) -> String {
+    format!("+{stat}: {}", upgrade_cost::probability_text(probability))
+}
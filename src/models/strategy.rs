@@ -0,0 +1,685 @@
+use crate::models::{
+    equipment::{self, SuccessBonus},
+    event,
+    golden_hammer::{self, GoldenHammer},
+    innocent::{self, InnocentScroll},
+    slot_transition::SlotTransition,
+    spell_trace, success_rate,
+    upgrade_context::UpgradeContext,
+};
+use std::ops::{Add, Mul};
+
+mod constants {
+    pub const INITIAL_RESTART_COST: f64 = 1e30;
+    pub const MAX_ITERATIONS: usize = 100;
+    pub const TOLERANCE: f64 = 1e-9;
+    pub const RESET_LABEL: &str = "새 장비로 재시작";
+    pub const CLEAN_SLATE_LABEL: &str = "순백의 주문서";
+    pub const INNOCENT_LABEL: &str = "이노센트 주문서";
+}
+
+pub struct ScrollOption {
+    pub label: &'static str,
+    pub transition: SlotTransition,
+    pub trace_required: u32,
+    pub cost: f64,
+    pub gain: u32,
+}
+
+#[derive(Clone, Copy)]
+pub struct CleanSlate {
+    pub success: f64,
+    pub price: f64,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Action {
+    Scroll(usize),
+    Restore(usize),
+    Reset,
+}
+
+#[derive(Clone, Copy)]
+pub struct State {
+    pub remaining: usize,
+    pub successes: usize,
+    pub gained: usize,
+}
+
+#[derive(Clone, Copy, Default)]
+struct Value {
+    cost: f64,
+    traces: f64,
+    clean_slates: f64,
+    innocents: f64,
+    reset: f64,
+}
+
+impl Value {
+    const RESET: Self = Self {
+        cost: 0.0,
+        traces: 0.0,
+        clean_slates: 0.0,
+        innocents: 0.0,
+        reset: 1.0,
+    };
+
+    fn total(self, restart_cost: f64) -> f64 {
+        self.cost + self.reset * restart_cost
+    }
+}
+
+impl Add for Value {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            cost: self.cost + other.cost,
+            traces: self.traces + other.traces,
+            clean_slates: self.clean_slates + other.clean_slates,
+            innocents: self.innocents + other.innocents,
+            reset: self.reset + other.reset,
+        }
+    }
+}
+
+impl Mul<f64> for Value {
+    type Output = Self;
+
+    fn mul(self, factor: f64) -> Self {
+        Self {
+            cost: self.cost * factor,
+            traces: self.traces * factor,
+            clean_slates: self.clean_slates * factor,
+            innocents: self.innocents * factor,
+            reset: self.reset * factor,
+        }
+    }
+}
+
+struct Table<T> {
+    slots: usize,
+    target: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Table<T> {
+    fn new(slots: usize, target: usize, initial: T) -> Self {
+        Self {
+            slots,
+            target,
+            cells: vec![initial; (slots + 1) * (slots + 1) * (target + 1)],
+        }
+    }
+
+    fn index(&self, state: State) -> usize {
+        (state.remaining * (self.slots + 1) + state.successes) * (self.target + 1) + state.gained
+    }
+
+    fn get(&self, state: State) -> T {
+        self.cells[self.index(state)].clone()
+    }
+
+    fn set(&mut self, state: State, value: T) {
+        let index = self.index(state);
+        self.cells[index] = value;
+    }
+}
+
+pub struct Strategy {
+    options: Vec<ScrollOption>,
+    clean_slate: Option<CleanSlate>,
+    innocent: Option<InnocentScroll>,
+    item_price: f64,
+    hammer: Option<GoldenHammer>,
+    bonus: Option<SuccessBonus>,
+    actions: Table<Action>,
+    pub expected_meso: f64,
+    pub expected_traces: f64,
+    pub expected_clean_slates: f64,
+    pub expected_innocents: f64,
+    pub item_success: f64,
+}
+
+pub struct PolicyRange {
+    pub from: u32,
+    pub to: u32,
+    pub label: String,
+}
+
+pub struct PolicyRow {
+    pub remaining: u32,
+    pub ranges: Vec<PolicyRange>,
+}
+
+impl Strategy {
+    pub fn start(&self) -> State {
+        State {
+            remaining: self.actions.slots,
+            successes: 0,
+            gained: 0,
+        }
+    }
+
+    pub fn target(&self) -> usize {
+        self.actions.target
+    }
+
+    pub fn action(&self, state: State) -> Action {
+        self.actions.get(state)
+    }
+
+    pub fn option(&self, index: usize) -> &ScrollOption {
+        &self.options[index]
+    }
+
+    pub fn clean_slate(&self) -> Option<CleanSlate> {
+        self.clean_slate
+    }
+
+    pub fn innocent(&self) -> Option<InnocentScroll> {
+        self.innocent
+    }
+
+    pub fn item_price(&self) -> f64 {
+        self.item_price
+    }
+
+    pub fn hammer(&self) -> Option<GoldenHammer> {
+        self.hammer
+    }
+
+    pub fn succeeded_state(&self, state: State, option: &ScrollOption) -> State {
+        succeeded_state(state, option, self.bonus, self.actions.target)
+    }
+
+    pub fn policy(&self) -> Vec<PolicyRow> {
+        policy(
+            &self.options,
+            self.innocent,
+            self.bonus,
+            (self.actions.slots, self.actions.target),
+            |state| Some(self.actions.get(state)),
+        )
+    }
+}
+
+pub fn scroll_options(context: &UpgradeContext) -> Option<Vec<ScrollOption>> {
+    let trace_price = f64::from(context.trace_price?);
+    let upgrade_salvation = event::upgrade_salvation(context)?;
+
+    equipment::available_spell_traces(context)
+        .into_iter()
+        .map(|spell_trace| {
+            let success_rate = success_rate::success_rate(context, spell_trace.success_rate)?;
+            let trace_required = spell_trace::trace_required(context, spell_trace);
+
+            Some(ScrollOption {
+                label: spell_trace.label.as_str(),
+                transition: SlotTransition::new(success_rate.probability(), upgrade_salvation),
+                trace_required,
+                cost: f64::from(trace_required) * trace_price,
+                gain: equipment::stat_gain(context, spell_trace)?,
+            })
+        })
+        .collect()
+}
+
+pub fn clean_slate(context: &UpgradeContext) -> Option<CleanSlate> {
+    let success_rate = context.clean_slate_success_rate.filter(|&rate| rate > 0)?;
+
+    Some(CleanSlate {
+        success: f64::from(success_rate) / 100.0,
+        price: f64::from(context.clean_slate_price?),
+    })
+}
+
+pub fn solve(context: &UpgradeContext) -> Option<Strategy> {
+    let slots = golden_hammer::slot_count(context)? as usize;
+    let target = context.target_stat? as usize;
+    let options = scroll_options(context)?;
+    let clean_slate = clean_slate(context);
+    let innocent = innocent::innocent_scroll(context);
+    let item_price = match innocent {
+        Some(_) => 0.0,
+        None => f64::from(context.item_price?),
+    };
+    let hammer = golden_hammer::golden_hammer(context);
+    let hammer_cost = golden_hammer::hammer_cost(context);
+    let bonus = equipment::success_bonus(context);
+    let mut restart_cost = constants::INITIAL_RESTART_COST;
+
+    for _ in 0..constants::MAX_ITERATIONS {
+        let solver = Solver {
+            options: &options,
+            clean_slate,
+            innocent,
+            item_price,
+            bonus,
+            restart_cost,
+        };
+        let (values, actions) = solver.evaluate(slots, target);
+        let start = values.get(State {
+            remaining: slots,
+            successes: 0,
+            gained: 0,
+        });
+
+        if start.reset >= 1.0 - constants::TOLERANCE {
+            return None;
+        }
+
+        let restarts = 1.0 / (1.0 - start.reset);
+        let expected_meso = (start.cost + hammer_cost) * restarts;
+
+        if (restart_cost - expected_meso).abs() <= constants::TOLERANCE * expected_meso {
+            return Some(Strategy {
+                options,
+                clean_slate,
+                innocent,
+                item_price,
+                hammer,
+                bonus,
+                actions,
+                expected_meso,
+                expected_traces: start.traces * restarts,
+                expected_clean_slates: start.clean_slates * restarts,
+                expected_innocents: start.innocents * restarts,
+                item_success: 1.0 - start.reset,
+            });
+        }
+
+        restart_cost = expected_meso;
+    }
+
+    None
+}
+
+struct Solver<'a> {
+    options: &'a [ScrollOption],
+    clean_slate: Option<CleanSlate>,
+    innocent: Option<InnocentScroll>,
+    item_price: f64,
+    bonus: Option<SuccessBonus>,
+    restart_cost: f64,
+}
+
+impl Solver<'_> {
+    fn evaluate(&self, slots: usize, target: usize) -> (Table<Value>, Table<Action>) {
+        let mut values = Table::new(slots, target, Value::default());
+        let mut actions = Table::new(slots, target, Action::Reset);
+
+        for successes in (0..=slots).rev() {
+            for gained in 0..target {
+                for remaining in 0..=slots - successes {
+                    let state = State {
+                        remaining,
+                        successes,
+                        gained,
+                    };
+                    let is_start = remaining == slots && gained == 0;
+                    let reset = (!is_start).then_some((self.reset_value(), Action::Reset));
+
+                    let (value, action) = if remaining == 0 {
+                        self.restore_candidates(&values, state, slots)
+                            .chain(reset)
+                            .min_by(|(a, _), (b, _)| self.compare(*a, *b))
+                    } else {
+                        self.scroll_candidates(&values, state, target)
+                            .chain(reset)
+                            .min_by(|(a, _), (b, _)| self.compare(*a, *b))
+                    }
+                    .unwrap_or((self.reset_value(), Action::Reset));
+
+                    values.set(state, value);
+                    actions.set(state, action);
+                }
+            }
+        }
+
+        (values, actions)
+    }
+
+    fn reset_value(&self) -> Value {
+        match self.innocent {
+            Some(innocent) => Value {
+                cost: innocent.expected_cost(),
+                innocents: innocent.expected_count(),
+                ..Value::RESET
+            },
+            None => Value {
+                cost: self.item_price,
+                ..Value::RESET
+            },
+        }
+    }
+
+    fn compare(&self, a: Value, b: Value) -> std::cmp::Ordering {
+        a.total(self.restart_cost).total_cmp(&b.total(self.restart_cost))
+    }
+
+    fn scroll_candidates(
+        &self,
+        values: &Table<Value>,
+        state: State,
+        target: usize,
+    ) -> impl Iterator<Item = (Value, Action)> {
+        self.options.iter().enumerate().map(move |(index, option)| {
+            let succeeded = values.get(succeeded_state(state, option, self.bonus, target));
+            let failed = values.get(failed_state(state));
+
+            (scroll_value(option, succeeded, failed), Action::Scroll(index))
+        })
+    }
+
+    fn restore_candidates(
+        &self,
+        values: &Table<Value>,
+        state: State,
+        slots: usize,
+    ) -> impl Iterator<Item = (Value, Action)> {
+        let restore =
+            self.clean_slate.filter(|_| state.successes < slots).map(|clean_slate| Value {
+                cost: clean_slate.price / clean_slate.success,
+                clean_slates: 1.0 / clean_slate.success,
+                ..Value::default()
+            });
+
+        restore.into_iter().flat_map(move |restore| {
+            self.options.iter().enumerate().map(move |(index, option)| {
+                let restored = State {
+                    remaining: 1,
+                    ..state
+                };
+                let succeeded =
+                    values.get(succeeded_state(restored, option, self.bonus, values.target));
+                let transition = option.transition;
+                let slot_consumed = transition.slot_consumed();
+                let scrolled =
+                    (own_value(option) + succeeded * transition.success) * (1.0 / slot_consumed);
+                let repeat = transition.slot_lost / slot_consumed;
+
+                ((restore + scrolled) * (1.0 / (1.0 - repeat)), Action::Restore(index))
+            })
+        })
+    }
+}
+
+pub fn succeeded_state(
+    state: State,
+    option: &ScrollOption,
+    bonus: Option<SuccessBonus>,
+    target: usize,
+) -> State {
+    let successes = state.successes + 1;
+    let bonus_gain =
+        equipment::bonus_gain(bonus, successes) - equipment::bonus_gain(bonus, state.successes);
+
+    State {
+        remaining: state.remaining - 1,
+        successes,
+        gained: (state.gained + option.gain as usize + bonus_gain).min(target),
+    }
+}
+
+pub fn failed_state(state: State) -> State {
+    State {
+        remaining: state.remaining - 1,
+        ..state
+    }
+}
+
+fn own_value(option: &ScrollOption) -> Value {
+    Value {
+        cost: option.cost,
+        traces: f64::from(option.trace_required),
+        ..Value::default()
+    }
+}
+
+fn scroll_value(option: &ScrollOption, succeeded: Value, failed: Value) -> Value {
+    let transition = option.transition;
+
+    (own_value(option) + succeeded * transition.success + failed * transition.slot_lost)
+        * (1.0 / transition.slot_consumed())
+}
+
+pub fn policy<F>(
+    options: &[ScrollOption],
+    innocent: Option<InnocentScroll>,
+    bonus: Option<SuccessBonus>,
+    (slots, target): (usize, usize),
+    action: F,
+) -> Vec<PolicyRow>
+where
+    F: Fn(State) -> Option<Action>,
+{
+    let reachable = reachable_states(options, bonus, (slots, target), &action);
+
+    (0..=slots)
+        .rev()
+        .map(|remaining| {
+            policy_row(remaining, (slots, target), |state| {
+                let action = action(state).filter(|_| reachable.get(state))?;
+
+                Some(action_label(options, innocent, action))
+            })
+        })
+        .filter(|row| !row.ranges.is_empty())
+        .collect()
+}
+
+fn reachable_states<F>(
+    options: &[ScrollOption],
+    bonus: Option<SuccessBonus>,
+    (slots, target): (usize, usize),
+    action: &F,
+) -> Table<bool>
+where
+    F: Fn(State) -> Option<Action>,
+{
+    let mut reachable = Table::new(slots, target, false);
+    let start = State {
+        remaining: slots,
+        successes: 0,
+        gained: 0,
+    };
+    let mut pending = vec![start];
+    reachable.set(start, true);
+
+    while let Some(state) = pending.pop() {
+        if state.gained >= target {
+            continue;
+        }
+
+        let next_states = match action(state) {
+            Some(Action::Scroll(index)) => {
+                let option = &options[index];
+                let mut next_states = vec![succeeded_state(state, option, bonus, target)];
+
+                if option.transition.slot_lost > 0.0 {
+                    next_states.push(failed_state(state));
+                }
+
+                next_states
+            }
+            Some(Action::Restore(_)) => vec![State {
+                remaining: 1,
+                ..state
+            }],
+            Some(Action::Reset) | None => Vec::new(),
+        };
+
+        for next_state in next_states {
+            if !reachable.get(next_state) {
+                reachable.set(next_state, true);
+                pending.push(next_state);
+            }
+        }
+    }
+
+    reachable
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn policy_row<F>(remaining: usize, (slots, target): (usize, usize), label: F) -> PolicyRow
+where
+    F: Fn(State) -> Option<String>,
+{
+    let mut ranges: Vec<PolicyRange> = Vec::new();
+
+    for gained in 0..target {
+        let mut labels: Vec<String> = Vec::new();
+
+        for successes in 0..=slots - remaining {
+            let state = State {
+                remaining,
+                successes,
+                gained,
+            };
+
+            if let Some(label) = label(state).filter(|label| !labels.contains(label)) {
+                labels.push(label);
+            }
+        }
+
+        if labels.is_empty() {
+            continue;
+        }
+
+        let label = labels.join(" / ");
+        let gained = gained as u32;
+
+        match ranges.last_mut() {
+            Some(range) if range.label == label => range.to = gained,
+            _ => ranges.push(PolicyRange {
+                from: gained,
+                to: gained,
+                label,
+            }),
+        }
+    }
+
+    PolicyRow {
+        remaining: remaining as u32,
+        ranges,
+    }
+}
+
+fn action_label(
+    options: &[ScrollOption],
+    innocent: Option<InnocentScroll>,
+    action: Action,
+) -> String {
+    match action {
+        Action::Scroll(index) => options[index].label.to_owned(),
+        Action::Restore(index) => {
+            format!("{} → {}", constants::CLEAN_SLATE_LABEL, options[index].label)
+        }
+        Action::Reset if innocent.is_some() => constants::INNOCENT_LABEL.to_owned(),
+        Action::Reset => constants::RESET_LABEL.to_owned(),
+    }
+}
+
+pub fn range_text(range: &PolicyRange) -> String {
+    if range.from == range.to {
+        format!("+{}: {}", range.from, range.label)
+    } else {
+        format!("+{} ~ +{}: {}", range.from, range.to, range.label)
+    }
+}
+
+pub fn row_label(row: &PolicyRow) -> String {
+    format!("남은 {}회", row.remaining)
+}
+
+pub fn scroll_count_text(count: f64) -> String {
+    format!("{count:.2}개")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Strategy, solve};
+    use crate::models::{
+        simulation::{self, StrategySimulation},
+        upgrade_context::UpgradeContext,
+    };
+
+    const TRACE_PRICE: u32 = 1_000;
+    const HIGHEST_TIER_MESO: f64 = 400_000.0;
+
+    fn context(upgradeable_count: u32, target_stat: u32) -> UpgradeContext {
+        UpgradeContext {
+            handicraft: Some(0),
+            enhance_mastery: Some(0),
+            upgrade_salvation: Some(0),
+            upgradeable_count: Some(upgradeable_count),
+            target_stat: Some(target_stat),
+            trace_price: Some(TRACE_PRICE),
+            item_price: Some(0),
+            ..UpgradeContext::default()
+        }
+    }
+
+    fn simulated_meso(context: &UpgradeContext, strategy: &Strategy) -> f64 {
+        match simulation::simulate_strategy(context, strategy) {
+            Some(StrategySimulation::Complete(simulation)) => simulation.mean_meso(),
+            _ => panic!("simulation did not complete"),
+        }
+    }
+
+    #[test]
+    fn single_slot_costs_one_scroll_per_success_chance() {
+        for upgrade_salvation in [0, 10] {
+            let context = UpgradeContext {
+                upgrade_salvation: Some(upgrade_salvation),
+                ..context(1, 9)
+            };
+            let strategy = solve(&context).unwrap();
+
+            assert!((strategy.expected_meso - HIGHEST_TIER_MESO / 0.15).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn failed_items_are_replaced_at_the_item_price() {
+        let item_price = 1_000_000.0;
+        let context = UpgradeContext {
+            item_price: Some(1_000_000),
+            ..context(1, 9)
+        };
+        let strategy = solve(&context).unwrap();
+        let expected = HIGHEST_TIER_MESO / 0.15 + item_price * (1.0 / 0.15 - 1.0);
+
+        assert!((strategy.expected_meso - expected).abs() < 1e-3);
+    }
+
+    #[test]
+    fn guaranteed_scrolls_cost_one_scroll_per_slot() {
+        let strategy = solve(&context(3, 9)).unwrap();
+
+        assert!((strategy.expected_meso - 3.0 * 75.0 * f64::from(TRACE_PRICE)).abs() < 1e-3);
+        assert!((strategy.item_success - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn unreachable_target_has_no_strategy() {
+        assert!(solve(&context(1, 10)).is_none());
+    }
+
+    #[test]
+    fn simulation_agrees_with_solved_expectation() {
+        let context = UpgradeContext {
+            upgrade_salvation: Some(5),
+            item_price: Some(2_000_000),
+            simulation_runs: Some(20_000),
+            simulation_seed: Some(7),
+            ..context(3, 15)
+        };
+        let strategy = solve(&context).unwrap();
+        let simulated = simulated_meso(&context, &strategy);
+        let error = (simulated - strategy.expected_meso).abs() / strategy.expected_meso;
+
+        assert!(error < 0.03, "relative error {error}");
+    }
+}
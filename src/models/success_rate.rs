@@ -0,0 +1,75 @@
+use crate::models::{event, game_data::game_data, upgrade_context::UpgradeContext};
+
+mod constants {
+    pub const MAX_SUCCESS_RATE: f64 = 100.0;
+}
+
+pub struct SuccessRate {
+    pub base: f64,
+    pub handicraft: f64,
+    pub enhance_mastery: f64,
+    pub event: f64,
+}
+
+impl SuccessRate {
+    pub fn total(&self) -> f64 {
+        self.sum().min(constants::MAX_SUCCESS_RATE)
+    }
+
+    pub fn probability(&self) -> f64 {
+        self.total() / 100.0
+    }
+
+    pub fn is_capped(&self) -> bool {
+        self.sum() > constants::MAX_SUCCESS_RATE
+    }
+
+    fn sum(&self) -> f64 {
+        self.base + self.handicraft + self.enhance_mastery + self.event
+    }
+}
+
+pub fn success_rate(context: &UpgradeContext, base: u32) -> Option<SuccessRate> {
+    Some(SuccessRate {
+        base: f64::from(base),
+        handicraft: handicraft_bonus(context.handicraft?),
+        enhance_mastery: enhance_mastery_bonus(context.enhance_mastery?),
+        event: f64::from(event::success_bonus(context)),
+    })
+}
+
+pub fn handicraft_bonus(handicraft_level: u32) -> f64 {
+    let guild_skills = &game_data().guild_skills;
+
+    f64::from(handicraft_level / guild_skills.handicraft_step)
+        * guild_skills.handicraft_bonus_per_step
+}
+
+pub fn enhance_mastery_bonus(enhance_mastery_level: u32) -> f64 {
+    f64::from(enhance_mastery_level) * game_data().guild_skills.enhance_mastery_bonus_per_level
+}
+
+pub fn upgrade_salvation_chance(upgrade_salvation_level: u32) -> f64 {
+    f64::from(upgrade_salvation_level) * game_data().guild_skills.upgrade_salvation_bonus_per_level
+}
+
+pub fn breakdown_text(success_rate: &SuccessRate) -> String {
+    let base = success_rate.base;
+    let handicraft = success_rate.handicraft;
+    let enhance_mastery = success_rate.enhance_mastery;
+    let event = if success_rate.event > 0.0 {
+        format!(" + 이벤트 {}%p", success_rate.event)
+    } else {
+        String::new()
+    };
+    let total = success_rate.total();
+    let cap = if success_rate.is_capped() {
+        " (최대)"
+    } else {
+        ""
+    };
+
+    format!(
+        "기본 {base}% + 손재주 {handicraft}%p + 강화의 달인 {enhance_mastery}%p{event} = {total}%{cap}"
+    )
+}
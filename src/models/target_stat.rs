@@ -0,0 +1,49 @@
+use crate::models::{
+    equipment, golden_hammer, innocent, stat_outcome, strategy::Strategy,
+    upgrade_context::UpgradeContext, upgrade_cost,
+};
+
+mod constants {
+    pub const OPTIMAL_LABEL: &str = "최적 전략";
+}
+
+pub struct TargetPlan {
+    pub label: &'static str,
+    pub probability: f64,
+    pub expected_meso: Option<f64>,
+}
+
+pub fn target_plans(
+    context: &UpgradeContext,
+    strategy: Option<&Strategy>,
+) -> Option<Vec<TargetPlan>> {
+    context.target_stat?;
+
+    let reset_meso =
+        innocent::innocent_scroll(context).map_or(0.0, |innocent| innocent.expected_cost());
+    let hammer_cost = golden_hammer::hammer_cost(context);
+
+    let fixed_plans =
+        equipment::available_spell_traces(context).into_iter().filter_map(|spell_trace| {
+            let probability =
+                stat_outcome::stat_outcome(context, spell_trace)?.target_probability?;
+            let pass_meso = upgrade_cost::tier_cost(context, spell_trace)?.meso + hammer_cost;
+
+            Some(TargetPlan {
+                label: spell_trace.label.as_str(),
+                probability,
+                expected_meso: per_success_meso(pass_meso, reset_meso, probability),
+            })
+        });
+    let optimal_plan = strategy.map(|strategy| TargetPlan {
+        label: constants::OPTIMAL_LABEL,
+        probability: strategy.item_success,
+        expected_meso: Some(strategy.expected_meso),
+    });
+
+    Some(fixed_plans.chain(optimal_plan).collect())
+}
+
+pub fn per_success_meso(pass_meso: f64, reset_meso: f64, probability: f64) -> Option<f64> {
+    (probability > 0.0).then(|| pass_meso / probability + (1.0 / probability - 1.0) * reset_meso)
+}
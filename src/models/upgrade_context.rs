@@ -1,60 +1,246 @@
+use crate::models::{budget, success_rate, upgrade_cost};
 use serde::{Deserialize, Serialize};
 
 pub mod spec_collection {
+    use crate::models::game_data::{self, SpecRange, SpecRanges};
+
     pub struct Spec {
         pub label: &'static str,
-        pub placeholder: &'static str,
-        pub min: u32,
-        pub max: u32,
+        pub placeholder: Option<&'static str>,
+        pub range: fn(&SpecRanges) -> SpecRange,
+    }
+
+    impl Spec {
+        pub fn min(&self) -> u32 {
+            (self.range)(&game_data::game_data().specs).min
+        }
+
+        pub fn max(&self) -> u32 {
+            (self.range)(&game_data::game_data().specs).max
+        }
+
+        pub fn placeholder(&self) -> String {
+            match self.placeholder {
+                Some(placeholder) => placeholder.to_owned(),
+                None => format!("{} ~ {}", self.min(), self.max()),
+            }
+        }
     }
 
     pub const HANDICRAFT: Spec = Spec {
         label: "손재주",
-        placeholder: "0 ~ 100",
-        min: 0,
-        max: 100,
+        placeholder: None,
+        range: |ranges| ranges.handicraft,
     };
 
     pub const ENHANCE_MASTERY: Spec = Spec {
         label: "강화의 달인",
-        placeholder: "0 ~ 4",
-        min: 0,
-        max: 4,
+        placeholder: None,
+        range: |ranges| ranges.enhance_mastery,
     };
 
     pub const UPGRADE_SALVATION: Spec = Spec {
         label: "실패를 두려워 않는",
-        placeholder: "0 ~ 4",
-        min: 0,
-        max: 4,
+        placeholder: None,
+        range: |ranges| ranges.upgrade_salvation,
+    };
+
+    pub const MVP_GRADE: Spec = Spec {
+        label: "MVP 등급",
+        placeholder: Some("선택"),
+        range: |ranges| ranges.mvp_grade,
+    };
+
+    pub const PC_ROOM: Spec = Spec {
+        label: "PC방",
+        placeholder: Some("선택"),
+        range: |ranges| ranges.pc_room,
     };
 
     pub const EQUIPMENT_LEVEL: Spec = Spec {
         label: "장비 레벨",
-        placeholder: "0 ~ 250",
-        min: 0,
-        max: 250,
+        placeholder: None,
+        range: |ranges| ranges.equipment_level,
+    };
+
+    pub const EQUIPMENT_CATEGORY: Spec = Spec {
+        label: "장비 분류",
+        placeholder: Some("선택"),
+        range: |ranges| ranges.equipment_category,
+    };
+
+    pub const EXISTING_SUCCESSES: Spec = Spec {
+        label: "이미 성공한 횟수",
+        placeholder: None,
+        range: |ranges| ranges.existing_successes,
     };
 
     pub const UPGRADEABLE_COUNT: Spec = Spec {
         label: "주문서 강화 가능 횟수",
-        placeholder: "1 ~ 12",
-        min: 1,
-        max: 12,
+        placeholder: None,
+        range: |ranges| ranges.upgradeable_count,
+    };
+
+    pub const SPELL_TRACE: Spec = Spec {
+        label: "주문서 성공 확률",
+        placeholder: Some("선택"),
+        range: |ranges| ranges.spell_trace,
     };
 
     pub const TRACE_REQUIRED: Spec = Spec {
         label: "주문의 흔적 필요 갯수 (썬데이 미적용 기준)",
-        placeholder: "0",
-        min: 0,
-        max: 10000,
+        placeholder: Some("자동 계산"),
+        range: |ranges| ranges.trace_required,
+    };
+
+    pub const TRACE_LIMIT: Spec = Spec {
+        label: "주문의 흔적 사용 한도",
+        placeholder: Some("0"),
+        range: |ranges| ranges.trace_limit,
+    };
+
+    pub const TARGET_STAT_KIND: Spec = Spec {
+        label: "목표 스탯 종류",
+        placeholder: Some("선택"),
+        range: |ranges| ranges.target_stat_kind,
+    };
+
+    pub const TARGET_STAT: Spec = Spec {
+        label: "목표 스탯 상승량",
+        placeholder: None,
+        range: |ranges| ranges.target_stat,
+    };
+
+    pub const CLEAN_SLATE_SUCCESS_RATE: Spec = Spec {
+        label: "순백의 주문서 성공 확률",
+        placeholder: None,
+        range: |ranges| ranges.clean_slate_success_rate,
+    };
+
+    pub const CLEAN_SLATE_PRICE: Spec = Spec {
+        label: "순백의 주문서 시세",
+        placeholder: Some("0"),
+        range: |ranges| ranges.clean_slate_price,
+    };
+
+    pub const INNOCENT_SUCCESS_RATE: Spec = Spec {
+        label: "이노센트 주문서 성공 확률",
+        placeholder: None,
+        range: |ranges| ranges.innocent_success_rate,
+    };
+
+    pub const INNOCENT_PRICE: Spec = Spec {
+        label: "이노센트 주문서 시세",
+        placeholder: Some("0"),
+        range: |ranges| ranges.innocent_price,
+    };
+
+    pub const ITEM_PRICE: Spec = Spec {
+        label: "장비 가격",
+        placeholder: Some("0"),
+        range: |ranges| ranges.item_price,
+    };
+
+    pub const GOLDEN_HAMMER_USED: Spec = Spec {
+        label: "황금망치 사용 여부",
+        placeholder: Some("선택"),
+        range: |ranges| ranges.golden_hammer_used,
+    };
+
+    pub const GOLDEN_HAMMER_SUCCESS_RATE: Spec = Spec {
+        label: "황금망치 성공 확률",
+        placeholder: None,
+        range: |ranges| ranges.golden_hammer_success_rate,
+    };
+
+    pub const GOLDEN_HAMMER_PRICE: Spec = Spec {
+        label: "황금망치 시세",
+        placeholder: Some("0"),
+        range: |ranges| ranges.golden_hammer_price,
+    };
+
+    pub const EVENT_ACTIVE: Spec = Spec {
+        label: "이벤트 적용",
+        placeholder: Some("선택"),
+        range: |ranges| ranges.event_active,
+    };
+
+    pub const EVENT_TRACE_DISCOUNT: Spec = Spec {
+        label: "주문의 흔적 할인율",
+        placeholder: None,
+        range: |ranges| ranges.event_trace_discount,
+    };
+
+    pub const EVENT_SUCCESS_BONUS: Spec = Spec {
+        label: "주문서 성공 확률 증가",
+        placeholder: None,
+        range: |ranges| ranges.event_success_bonus,
+    };
+
+    pub const EVENT_SLOT_PROTECTION: Spec = Spec {
+        label: "실패 시 횟수 차감 방지",
+        placeholder: Some("선택"),
+        range: |ranges| ranges.event_slot_protection,
+    };
+
+    pub const CHAOS_SCROLL: Spec = Spec {
+        label: "혼돈의 주문서 종류",
+        placeholder: Some("선택"),
+        range: |ranges| ranges.chaos_scroll,
+    };
+
+    pub const CHAOS_PRICE: Spec = Spec {
+        label: "혼돈의 주문서 시세",
+        placeholder: Some("0"),
+        range: |ranges| ranges.chaos_price,
+    };
+
+    pub const SIMULATION_RUNS: Spec = Spec {
+        label: "시뮬레이션 횟수",
+        placeholder: None,
+        range: |ranges| ranges.simulation_runs,
+    };
+
+    pub const SIMULATION_SEED: Spec = Spec {
+        label: "시드",
+        placeholder: Some("0"),
+        range: |ranges| ranges.simulation_seed,
     };
 
     pub const TRACE_PRICE: Spec = Spec {
         label: "주문의 흔적 시세",
-        placeholder: "0",
-        min: 0,
-        max: 10000,
+        placeholder: Some("0"),
+        range: |ranges| ranges.trace_price,
+    };
+
+    pub const PET_SCROLL: Spec = Spec {
+        label: "펫장비 주문서 종류",
+        placeholder: Some("선택"),
+        range: |ranges| ranges.pet_scroll,
+    };
+
+    pub const PET_UPGRADEABLE_COUNT: Spec = Spec {
+        label: "펫장비 강화 가능 횟수",
+        placeholder: None,
+        range: |ranges| ranges.pet_upgradeable_count,
+    };
+
+    pub const PET_SCROLL_PRICE: Spec = Spec {
+        label: "펫장비 주문서 가격",
+        placeholder: Some("0"),
+        range: |ranges| ranges.pet_scroll_price,
+    };
+
+    pub const CASH_EXCHANGE_RATE: Spec = Spec {
+        label: "캐시 1당 메소",
+        placeholder: Some("0"),
+        range: |ranges| ranges.cash_exchange_rate,
+    };
+
+    pub const MESO_BUDGET: Spec = Spec {
+        label: "메소 예산 (만 메소)",
+        placeholder: Some("0"),
+        range: |ranges| ranges.meso_budget,
     };
 }
 
@@ -63,24 +249,96 @@ pub struct UpgradeContext {
     pub handicraft: Option<u32>,
     pub enhance_mastery: Option<u32>,
     pub upgrade_salvation: Option<u32>,
+    pub mvp_grade: Option<u32>,
+    pub pc_room: Option<bool>,
     pub equipment_level: Option<u32>,
+    pub equipment_category: Option<u32>,
     pub upgradeable_count: Option<u32>,
+    pub existing_successes: Option<u32>,
+    pub spell_trace: Option<u32>,
     pub trace_required: Option<u32>,
     pub trace_price: Option<u32>,
+    pub trace_limit: Option<u32>,
+    pub target_stat_kind: Option<u32>,
+    pub target_stat: Option<u32>,
+    pub clean_slate_success_rate: Option<u32>,
+    pub clean_slate_price: Option<u32>,
+    pub innocent_success_rate: Option<u32>,
+    pub innocent_price: Option<u32>,
+    pub item_price: Option<u32>,
+    pub golden_hammer_used: Option<bool>,
+    pub golden_hammer_success_rate: Option<u32>,
+    pub golden_hammer_price: Option<u32>,
+    pub chaos_scroll: Option<u32>,
+    pub chaos_price: Option<u32>,
+    pub event_active: Option<bool>,
+    pub event_trace_discount: Option<u32>,
+    pub event_success_bonus: Option<u32>,
+    pub event_slot_protection: Option<bool>,
+    pub simulation_runs: Option<u32>,
+    pub simulation_seed: Option<u32>,
+    pub pet_scroll: Option<u32>,
+    pub pet_upgradeable_count: Option<u32>,
+    pub pet_scroll_price: Option<u32>,
+    pub cash_exchange_rate: Option<u32>,
+    pub meso_budget: Option<u32>,
 }
 
 pub fn handicraft_tooltip(handicraft_level: u32) -> String {
-    format!("성공 확률 {}%p 증가", f64::from(handicraft_level / 5 * 5) / 10.0)
+    format!("성공 확률 {}%p 증가", success_rate::handicraft_bonus(handicraft_level))
 }
 
 pub fn enhance_mastery_tooltip(enhance_mastery_level: u32) -> String {
-    format!("성공 확률 {enhance_mastery_level}%p 증가")
+    format!("성공 확률 {}%p 증가", success_rate::enhance_mastery_bonus(enhance_mastery_level))
 }
 
 pub fn upgrade_salvation_tooltip(upgrade_salvation_level: u32) -> String {
-    format!("실패 시 {upgrade_salvation_level}% 확률로 횟수 차감 방지")
+    format!(
+        "실패 시 {}% 확률로 횟수 차감 방지",
+        success_rate::upgrade_salvation_chance(upgrade_salvation_level)
+    )
 }
 
 pub fn trace_price_tooltip(trace_price: u32) -> String {
     format!("{trace_price} 메소")
 }
+
+pub fn clean_slate_price_tooltip(clean_slate_price: u32) -> String {
+    format!("{clean_slate_price} 메소")
+}
+
+pub fn innocent_price_tooltip(innocent_price: u32) -> String {
+    format!("{innocent_price} 메소")
+}
+
+pub fn item_price_tooltip(item_price: u32) -> String {
+    format!("{item_price} 메소")
+}
+
+pub fn golden_hammer_price_tooltip(golden_hammer_price: u32) -> String {
+    format!("{golden_hammer_price} 메소")
+}
+
+pub fn event_trace_discount_tooltip(event_trace_discount: u32) -> String {
+    format!("주문의 흔적 필요 갯수 {event_trace_discount}% 감소")
+}
+
+pub fn event_success_bonus_tooltip(event_success_bonus: u32) -> String {
+    format!("성공 확률 {event_success_bonus}%p 증가")
+}
+
+pub fn chaos_price_tooltip(chaos_price: u32) -> String {
+    format!("{chaos_price} 메소")
+}
+
+pub fn pet_scroll_price_tooltip(pet_scroll_price: u32) -> String {
+    format!("{pet_scroll_price} 메소 (캐시 주문서는 캐시)")
+}
+
+pub fn cash_exchange_rate_tooltip(cash_exchange_rate: u32) -> String {
+    format!("1 캐시 = {cash_exchange_rate} 메소")
+}
+
+pub fn meso_budget_tooltip(meso_budget: u32) -> String {
+    upgrade_cost::meso_text(budget::budget_meso(meso_budget))
+}
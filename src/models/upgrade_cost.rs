@@ -0,0 +1,200 @@
+use crate::models::{
+    distribution::Distribution,
+    event, golden_hammer,
+    slot_transition::SlotTransition,
+    spell_trace::{self, SpellTrace},
+    strategy, success_rate,
+    upgrade_context::{UpgradeContext, spec_collection},
+};
+
+pub const PERCENTILES: [f64; 3] = [0.5, 0.9, 0.99];
+
+pub struct UpgradeCost {
+    pub attempts: f64,
+    pub successes: f64,
+    pub traces: f64,
+    pub meso: f64,
+}
+
+pub struct CostDistribution {
+    attempts: Distribution,
+    trace_required: u32,
+    trace_price: u32,
+}
+
+impl CostDistribution {
+    #[allow(clippy::cast_precision_loss)]
+    pub fn traces_percentile(&self, percentile: f64) -> f64 {
+        self.attempts.percentile(percentile) as f64 * f64::from(self.trace_required)
+    }
+
+    pub fn meso_percentile(&self, percentile: f64) -> f64 {
+        self.traces_percentile(percentile) * f64::from(self.trace_price)
+    }
+
+    pub fn within_traces(&self, traces: u32) -> f64 {
+        match traces.checked_div(self.trace_required) {
+            Some(attempts) => self.attempts.cdf(attempts as usize),
+            None => 1.0,
+        }
+    }
+}
+
+pub struct SalvationOutcome {
+    pub level: u32,
+    pub successes: f64,
+    pub target_traces: Option<f64>,
+    pub target_meso: Option<f64>,
+}
+
+pub fn expected_cost(context: &UpgradeContext) -> Option<UpgradeCost> {
+    tier_cost(context, spell_trace::selected(context)?)
+}
+
+pub fn tier_cost(context: &UpgradeContext, spell_trace: &SpellTrace) -> Option<UpgradeCost> {
+    let transition = tier_transition(context, spell_trace)?;
+    let slots = f64::from(golden_hammer::slot_count(context)?);
+    let trace_required = f64::from(spell_trace::trace_required(context, spell_trace));
+    let trace_price = f64::from(context.trace_price?);
+
+    let attempts = slots * transition.attempts_per_slot();
+    let traces = attempts * trace_required;
+
+    Some(UpgradeCost {
+        attempts,
+        successes: slots * transition.successes_per_slot(),
+        traces,
+        meso: traces * trace_price,
+    })
+}
+
+pub fn cost_distribution(context: &UpgradeContext) -> Option<CostDistribution> {
+    let transition = slot_transition(context)?;
+    let attempts = Distribution::geometric(transition.slot_consumed())
+        .repeat(golden_hammer::slot_count(context)?);
+
+    Some(CostDistribution {
+        attempts,
+        trace_required: spell_trace::selected_trace_required(context)?,
+        trace_price: context.trace_price?,
+    })
+}
+
+pub fn salvation_comparison(context: &UpgradeContext) -> Option<[SalvationOutcome; 2]> {
+    let outcome = |level: u32| {
+        let context = UpgradeContext {
+            upgrade_salvation: Some(level),
+            ..context.clone()
+        };
+        let strategy = strategy::solve(&context);
+
+        Some(SalvationOutcome {
+            level,
+            successes: expected_cost(&context)?.successes,
+            target_traces: strategy.as_ref().map(|strategy| strategy.expected_traces),
+            target_meso: strategy.as_ref().map(|strategy| strategy.expected_meso),
+        })
+    };
+
+    Some([
+        outcome(spec_collection::UPGRADE_SALVATION.min())?,
+        outcome(spec_collection::UPGRADE_SALVATION.max())?,
+    ])
+}
+
+pub fn slot_transition(context: &UpgradeContext) -> Option<SlotTransition> {
+    tier_transition(context, spell_trace::selected(context)?)
+}
+
+pub fn tier_transition(
+    context: &UpgradeContext,
+    spell_trace: &SpellTrace,
+) -> Option<SlotTransition> {
+    let success = success_rate::success_rate(context, spell_trace.success_rate)?.probability();
+
+    Some(SlotTransition::new(success, event::upgrade_salvation(context)?))
+}
+
+pub fn attempts_text(attempts: f64) -> String {
+    format!("{attempts:.2}회")
+}
+
+pub fn successes_text(successes: f64) -> String {
+    format!("{successes:.2}회 성공")
+}
+
+pub fn traces_text(traces: f64) -> String {
+    format!("{traces:.0}개")
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub fn meso_text(meso: f64) -> String {
+    let meso = meso.round() as u64;
+    let eok = meso / 100_000_000;
+    let man = meso / 10_000 % 10_000;
+    let rest = meso % 10_000;
+
+    let units: Vec<String> = [(eok, "억"), (man, "만"), (rest, "")]
+        .into_iter()
+        .filter(|(value, _)| *value > 0)
+        .map(|(value, unit)| format!("{value}{unit}"))
+        .collect();
+
+    if units.is_empty() {
+        "0 메소".to_owned()
+    } else {
+        format!("{} 메소", units.join(" "))
+    }
+}
+
+pub fn percentile_label(percentile: f64) -> String {
+    format!("P{}", percentile * 100.0)
+}
+
+pub fn percentile_text(traces: f64, meso: f64) -> String {
+    format!("{} · {}", traces_text(traces), meso_text(meso))
+}
+
+pub fn probability_text(probability: f64) -> String {
+    format!("{:.2}%", probability * 100.0)
+}
+
+pub fn salvation_successes_text(outcomes: &[SalvationOutcome; 2]) -> String {
+    let [without, with] = outcomes;
+
+    format!(
+        "{}레벨 {} → {}레벨 {}",
+        without.level,
+        successes_text(without.successes),
+        with.level,
+        successes_text(with.successes)
+    )
+}
+
+pub fn salvation_traces_text(outcomes: &[SalvationOutcome; 2]) -> Option<String> {
+    let [without, with] = outcomes;
+    let (without_traces, with_traces) = (without.target_traces?, with.target_traces?);
+
+    Some(format!(
+        "{}레벨 {} → {}레벨 {} ({} 절약)",
+        without.level,
+        traces_text(without_traces),
+        with.level,
+        traces_text(with_traces),
+        traces_text(without_traces - with_traces)
+    ))
+}
+
+pub fn salvation_meso_text(outcomes: &[SalvationOutcome; 2]) -> Option<String> {
+    let [without, with] = outcomes;
+    let (without_meso, with_meso) = (without.target_meso?, with.target_meso?);
+
+    Some(format!(
+        "{}레벨 {} → {}레벨 {} ({} 절약)",
+        without.level,
+        meso_text(without_meso),
+        with.level,
+        meso_text(with_meso),
+        meso_text(without_meso - with_meso)
+    ))
+}
@@ -77,6 +77,7 @@ impl FromStr for BadResponse {
     }
 }
 
+#[allow(dead_code)]
 #[derive(Deserialize)]
 struct Error {
     name: String,
@@ -3,7 +3,7 @@ use sycamore::{
     prelude::*,
     web::events::{EventDescriptor, EventHandler},
 };
-use web_sys::{Event, HtmlInputElement, wasm_bindgen::JsCast};
+use web_sys::{Event, File, HtmlInputElement, HtmlSelectElement, wasm_bindgen::JsCast};
 
 pub trait ViewVecExt {
     fn join<F>(self, separator_fn: F) -> Vec<View>
@@ -75,17 +75,38 @@ pub trait EventValue {
 impl EventValue for Event {
     fn value(&self) -> Option<String> {
         let target = self.target()?;
-        let input = target.dyn_into::<HtmlInputElement>().ok()?;
-        Some(input.value())
+
+        match target.dyn_into::<HtmlInputElement>() {
+            Ok(input) => Some(input.value()),
+            Err(target) => target.dyn_into::<HtmlSelectElement>().ok().map(|select| select.value()),
+        }
     }
 }
 
 pub trait EventParser {
     fn parse(&self) -> Option<u32>;
+
+    fn parse_bool(&self) -> Option<bool>;
 }
 
 impl EventParser for Event {
     fn parse(&self) -> Option<u32> {
         self.value().and_then(|value| value.parse().ok())
     }
+
+    fn parse_bool(&self) -> Option<bool> {
+        self.value().and_then(|value| value.parse().ok())
+    }
+}
+
+pub trait EventFile {
+    fn file(&self) -> Option<File>;
+}
+
+impl EventFile for Event {
+    fn file(&self) -> Option<File> {
+        let input = self.target()?.dyn_into::<HtmlInputElement>().ok()?;
+
+        input.files()?.get(0)
+    }
 }
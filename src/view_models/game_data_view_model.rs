@@ -0,0 +1,83 @@
+use crate::{
+    models::game_data,
+    utils::sycamore::{Callback, EventFile},
+};
+use gloo_storage::{LocalStorage, Storage};
+use sycamore::prelude::*;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{Event, File};
+
+mod constants {
+    pub const GAME_DATA_STORAGE_KEY: &str = "game_data";
+    pub const READ_ERROR: &str = "파일을 읽을 수 없습니다";
+}
+
+#[derive(Clone)]
+pub struct GameDataViewModel {
+    pub version: String,
+    pub overridden: bool,
+    pub stored: bool,
+    pub error: Signal<Option<String>>,
+}
+
+impl GameDataViewModel {
+    pub fn new(installed: Result<bool, String>) -> Self {
+        Self {
+            version: game_data::version_text(game_data::game_data()),
+            overridden: installed == Ok(true),
+            stored: installed != Ok(false),
+            error: create_signal(installed.err()),
+        }
+    }
+
+    pub fn install_stored_data() -> Result<bool, String> {
+        let Ok(json) = LocalStorage::get::<String>(constants::GAME_DATA_STORAGE_KEY) else {
+            return Ok(false);
+        };
+
+        game_data::install(game_data::parse(&json)?)?;
+
+        Ok(true)
+    }
+
+    pub fn file_change_callback(&self) -> Callback {
+        let error = self.error;
+
+        Callback::from(move |event: Event| {
+            if let Some(file) = event.file() {
+                wasm_bindgen_futures::spawn_local(async move {
+                    Self::load_file(error, file).await;
+                });
+            }
+        })
+    }
+
+    pub fn reset_callback() -> Callback {
+        Callback::from(move |_event: Event| {
+            LocalStorage::delete(constants::GAME_DATA_STORAGE_KEY);
+            Self::reload();
+        })
+    }
+
+    async fn load_file(error: Signal<Option<String>>, file: File) {
+        let Some(json) = JsFuture::from(file.text()).await.ok().and_then(|text| text.as_string())
+        else {
+            error.set(Some(constants::READ_ERROR.to_owned()));
+            return;
+        };
+
+        match game_data::parse(&json) {
+            Ok(_) => {
+                LocalStorage::set(constants::GAME_DATA_STORAGE_KEY, json).unwrap();
+                Self::reload();
+            }
+            Err(message) => error.set(Some(message)),
+        }
+    }
+
+    fn reload() {
+        if let Some(window) = web_sys::window() {
+            window.location().reload().unwrap();
+        }
+    }
+}
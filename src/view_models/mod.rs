@@ -1,2 +1,4 @@
+pub mod game_data_view_model;
+pub mod starforce_view_model;
 pub mod theme_view_model;
 pub mod upgrade_context_view_model;
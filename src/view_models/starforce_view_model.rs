@@ -0,0 +1,263 @@
+pub use crate::models::{
+    starforce::{
+        self, OptionOutcome, SafeguardPolicy, StarforceCost, StarforceDistribution, StarforceInputs,
+    },
+    starforce_context::{
+        StarforceContext,
+        spec_collection::{self, Spec},
+    },
+    starforce_event::{self, EventOutcome},
+    starforce_membership::{self, MembershipOutcome},
+    upgrade_context::UpgradeContext,
+    upgrade_cost::{self, PERCENTILES},
+};
+use crate::{
+    models::starforce_context,
+    utils::sycamore::{Callback, EventParser},
+};
+use gloo_storage::{LocalStorage, Storage};
+use sycamore::prelude::*;
+use web_sys::Event;
+
+mod constants {
+    pub const STARFORCE_CONTEXT_STORAGE_KEY: &str = "starforce_context";
+}
+
+#[derive(Clone)]
+pub struct StarforceViewModel {
+    pub current_starforce_context: Signal<StarforceContext>,
+    pub equipment_level: ReadSignal<Option<u32>>,
+    pub recovery_meso: ReadSignal<Option<f64>>,
+    pub inputs: ReadSignal<Option<StarforceInputs>>,
+    pub expected_cost: ReadSignal<Option<StarforceCost>>,
+    pub cost_distribution: ReadSignal<Option<StarforceDistribution>>,
+}
+
+impl StarforceViewModel {
+    pub fn new(
+        current_upgrade_context: Signal<UpgradeContext>,
+        rebuild_meso: ReadSignal<Option<f64>>,
+    ) -> Self {
+        let stored_starforce_context: StarforceContext =
+            LocalStorage::get(constants::STARFORCE_CONTEXT_STORAGE_KEY).unwrap_or_default();
+
+        let current_starforce_context = create_signal(stored_starforce_context);
+        let equipment_level = create_selector(move || {
+            current_upgrade_context.with(|context| context.equipment_level)
+        });
+        let recovery_meso = create_selector(move || {
+            current_starforce_context
+                .with(|context| starforce::recovery_meso(context, rebuild_meso.get()))
+        });
+        let inputs = create_selector(move || {
+            let (mvp_grade, pc_room) = current_upgrade_context
+                .with(|context| (context.mvp_grade, context.pc_room.unwrap_or_default()));
+
+            Some(StarforceInputs {
+                equipment_level: equipment_level.get()?,
+                mvp_grade,
+                pc_room,
+                recovery: recovery_meso.get()?,
+            })
+        });
+
+        Self {
+            current_starforce_context,
+            equipment_level,
+            recovery_meso,
+            inputs,
+            expected_cost: create_memo(move || {
+                let inputs = inputs.get()?;
+                current_starforce_context.with(|context| starforce::expected_cost(context, inputs))
+            }),
+            cost_distribution: create_memo(move || {
+                let inputs = inputs.get()?;
+                current_starforce_context
+                    .with(|context| starforce::cost_distribution(context, inputs))
+            }),
+        }
+    }
+
+    pub fn get_field<F>(&self, field_getter: F) -> Option<String>
+    where
+        F: Fn(&StarforceContext) -> Option<u32>,
+    {
+        let starforce_context = self.current_starforce_context.get_clone();
+        field_getter(&starforce_context).map(|x| x.to_string())
+    }
+
+    pub fn get_toggle<F>(&self, field_getter: F) -> Option<bool>
+    where
+        F: Fn(&StarforceContext) -> Option<bool>,
+    {
+        let starforce_context = self.current_starforce_context.get_clone();
+        field_getter(&starforce_context)
+    }
+
+    fn create_callback<F>(&self, spec: &Spec, field_setter: F) -> Callback
+    where
+        F: Fn(&mut StarforceContext, Option<u32>) + 'static,
+    {
+        let current_starforce_context = self.current_starforce_context;
+        let min = spec.min();
+        let max = spec.max();
+
+        Callback::from(move |event: Event| {
+            if let Some(value) = event.parse()
+                && (min..=max).contains(&value)
+            {
+                let mut starforce_context = current_starforce_context.get_clone_untracked();
+                field_setter(&mut starforce_context, Some(value));
+                current_starforce_context.set(starforce_context.clone());
+                LocalStorage::set(constants::STARFORCE_CONTEXT_STORAGE_KEY, starforce_context)
+                    .unwrap();
+            }
+        })
+    }
+
+    fn create_toggle_callback<F>(&self, field_setter: F) -> Callback
+    where
+        F: Fn(&mut StarforceContext, Option<bool>) + 'static,
+    {
+        let current_starforce_context = self.current_starforce_context;
+
+        Callback::from(move |event: Event| {
+            if let Some(value) = event.parse_bool() {
+                let mut starforce_context = current_starforce_context.get_clone_untracked();
+                field_setter(&mut starforce_context, Some(value));
+                current_starforce_context.set(starforce_context.clone());
+                LocalStorage::set(constants::STARFORCE_CONTEXT_STORAGE_KEY, starforce_context)
+                    .unwrap();
+            }
+        })
+    }
+
+    fn create_tooltip<F>(&self, field_getter: F, tooltip_fn: fn(u32) -> String) -> String
+    where
+        F: Fn(&StarforceContext) -> Option<u32>,
+    {
+        let starforce_context = self.current_starforce_context.get_clone_untracked();
+        let value = field_getter(&starforce_context).unwrap_or_default();
+
+        tooltip_fn(value)
+    }
+
+    pub fn current_star_change_callback(&self) -> Callback {
+        self.create_callback(&spec_collection::CURRENT_STAR, |context, value| {
+            context.current_star = value;
+        })
+    }
+
+    pub fn target_star_change_callback(&self) -> Callback {
+        self.create_callback(&spec_collection::TARGET_STAR, |context, value| {
+            context.target_star = value;
+        })
+    }
+
+    pub fn star_catch_change_callback(&self) -> Callback {
+        self.create_toggle_callback(|context, value| {
+            context.star_catch = value;
+        })
+    }
+
+    pub fn starforce_event_change_callback(&self) -> Callback {
+        self.create_callback(&spec_collection::STARFORCE_EVENT, |context, value| {
+            context.starforce_event = value;
+        })
+    }
+
+    pub fn recovery_method_change_callback(&self) -> Callback {
+        self.create_callback(&spec_collection::RECOVERY_METHOD, |context, value| {
+            context.recovery_method = value;
+        })
+    }
+
+    pub fn spare_price_change_callback(&self) -> Callback {
+        self.create_callback(&spec_collection::SPARE_PRICE, |context, value| {
+            context.spare_price = value;
+        })
+    }
+
+    pub fn restoration_cost_change_callback(&self) -> Callback {
+        self.create_callback(&spec_collection::RESTORATION_COST, |context, value| {
+            context.restoration_cost = value;
+        })
+    }
+
+    pub fn safeguard_value(&self, star: u32) -> bool {
+        let safeguard_stars =
+            self.current_starforce_context.with(|context| context.safeguard_stars);
+
+        starforce::is_marked(safeguard_stars.unwrap_or_default(), star)
+    }
+
+    pub fn safeguard_change_callback(&self, star: u32) -> Callback {
+        let current_starforce_context = self.current_starforce_context;
+
+        Callback::from(move |event: Event| {
+            let mut starforce_context = current_starforce_context.get_clone_untracked();
+            let safeguard_stars = starforce_context.safeguard_stars.unwrap_or_default();
+
+            starforce_context.safeguard_stars = match event.parse_bool() {
+                Some(false) => Some(starforce::unmark(safeguard_stars, star)),
+                Some(true) => Some(starforce::mark(safeguard_stars, star)),
+                None => return,
+            };
+            current_starforce_context.set(starforce_context.clone());
+            LocalStorage::set(constants::STARFORCE_CONTEXT_STORAGE_KEY, starforce_context).unwrap();
+        })
+    }
+
+    pub fn option_comparison(&self) -> ReadSignal<Option<Vec<OptionOutcome>>> {
+        let current_starforce_context = self.current_starforce_context;
+        let inputs = self.inputs;
+
+        create_memo(move || {
+            let inputs = inputs.get()?;
+            current_starforce_context.with(|context| starforce::option_comparison(context, inputs))
+        })
+    }
+
+    pub fn safeguard_policy(&self) -> ReadSignal<Option<SafeguardPolicy>> {
+        let current_starforce_context = self.current_starforce_context;
+        let inputs = self.inputs;
+
+        create_memo(move || {
+            let inputs = inputs.get()?;
+            current_starforce_context.with(|context| starforce::safeguard_policy(context, inputs))
+        })
+    }
+
+    pub fn event_comparison(&self) -> ReadSignal<Option<Vec<EventOutcome>>> {
+        let current_starforce_context = self.current_starforce_context;
+        let inputs = self.inputs;
+
+        create_memo(move || {
+            let inputs = inputs.get()?;
+            current_starforce_context
+                .with(|context| starforce_event::event_comparison(context, inputs))
+        })
+    }
+
+    pub fn membership_comparison(&self) -> ReadSignal<Option<Vec<MembershipOutcome>>> {
+        let current_starforce_context = self.current_starforce_context;
+        let inputs = self.inputs;
+
+        create_memo(move || {
+            let inputs = inputs.get()?;
+            current_starforce_context
+                .with(|context| starforce_membership::membership_comparison(context, inputs))
+        })
+    }
+
+    pub fn spare_price_tooltip(&self) -> String {
+        self.create_tooltip(|context| context.spare_price, starforce_context::spare_price_tooltip)
+    }
+
+    pub fn restoration_cost_tooltip(&self) -> String {
+        self.create_tooltip(
+            |context| context.restoration_cost,
+            starforce_context::restoration_cost_tooltip,
+        )
+    }
+}
@@ -1,6 +1,25 @@
-pub use crate::models::upgrade_context::{
-    UpgradeContext,
-    spec_collection::{self, Spec},
+pub use crate::models::{
+    budget::{self, BudgetPlan},
+    chaos::{self, ChaosOutcome},
+    distribution::Distribution,
+    equipment::{self, EQUIPMENT_CATEGORIES, StatKind},
+    event::{self, EventComparison},
+    golden_hammer::{self, HammerSlot},
+    innocent::{self, ResetPlan},
+    pet_equipment::{self, PetOutcome},
+    sensitivity::{self, SensitivityRow},
+    simulation::{self, Simulation, StrategySimulation},
+    spell_trace::{self, SpellTrace, TraceRequirement},
+    starforce_membership,
+    stat_outcome::{self, StatOutcome},
+    strategy::{self, PolicyRow, Strategy},
+    success_rate::{self, SuccessRate},
+    target_stat::{self, TargetPlan},
+    upgrade_context::{
+        UpgradeContext,
+        spec_collection::{self, Spec},
+    },
+    upgrade_cost::{self, CostDistribution, PERCENTILES, SalvationOutcome, UpgradeCost},
 };
 use crate::{
     models::upgrade_context,
@@ -20,6 +39,11 @@ mod constants {
 #[derive(Clone)]
 pub struct UpgradeContextViewModel {
     pub current_upgrade_context: Signal<UpgradeContext>,
+    pub expected_cost: ReadSignal<Option<UpgradeCost>>,
+    pub cost_distribution: ReadSignal<Option<CostDistribution>>,
+    pub strategy: ReadSignal<Option<Strategy>>,
+    pub sensitivity_shown: Signal<bool>,
+    pub simulation_shown: Signal<bool>,
 }
 
 impl UpgradeContextViewModel {
@@ -27,8 +51,19 @@ impl UpgradeContextViewModel {
         let stored_upgrade_context: UpgradeContext =
             LocalStorage::get(constants::UPGRADE_CONTEXT_STORAGE_KEY).unwrap_or_default();
 
+        let current_upgrade_context = create_signal(stored_upgrade_context);
+
         Self {
-            current_upgrade_context: create_signal(stored_upgrade_context),
+            current_upgrade_context,
+            expected_cost: create_memo(move || {
+                current_upgrade_context.with(upgrade_cost::expected_cost)
+            }),
+            cost_distribution: create_memo(move || {
+                current_upgrade_context.with(upgrade_cost::cost_distribution)
+            }),
+            strategy: create_memo(move || current_upgrade_context.with(strategy::solve)),
+            sensitivity_shown: create_signal(false),
+            simulation_shown: create_signal(false),
         }
     }
 
@@ -68,13 +103,21 @@ impl UpgradeContextViewModel {
         LocalStorage::set(constants::UPGRADE_CONTEXT_STORAGE_KEY, upgrade_context).unwrap();
     }
 
+    pub fn get_toggle<F>(&self, field_getter: F) -> Option<bool>
+    where
+        F: Fn(&UpgradeContext) -> Option<bool>,
+    {
+        let upgrade_context = self.current_upgrade_context.get_clone();
+        field_getter(&upgrade_context)
+    }
+
     fn create_callback<F>(&self, spec: &Spec, field_setter: F) -> Callback
     where
         F: Fn(&mut UpgradeContext, Option<u32>) + 'static,
     {
         let current_upgrade_context = self.current_upgrade_context;
-        let min = spec.min;
-        let max = spec.max;
+        let min = spec.min();
+        let max = spec.max();
 
         Callback::from(move |event: Event| {
             if let Some(value) = event.parse()
@@ -88,6 +131,44 @@ impl UpgradeContextViewModel {
         })
     }
 
+    fn create_toggle_callback<F>(&self, field_setter: F) -> Callback
+    where
+        F: Fn(&mut UpgradeContext, Option<bool>) + 'static,
+    {
+        let current_upgrade_context = self.current_upgrade_context;
+
+        Callback::from(move |event: Event| {
+            if let Some(value) = event.parse_bool() {
+                let mut upgrade_context = current_upgrade_context.get_clone_untracked();
+                field_setter(&mut upgrade_context, Some(value));
+                current_upgrade_context.set(upgrade_context.clone());
+                LocalStorage::set(constants::UPGRADE_CONTEXT_STORAGE_KEY, upgrade_context).unwrap();
+            }
+        })
+    }
+
+    fn create_optional_callback<F>(&self, spec: &Spec, field_setter: F) -> Callback
+    where
+        F: Fn(&mut UpgradeContext, Option<u32>) + 'static,
+    {
+        let current_upgrade_context = self.current_upgrade_context;
+        let min = spec.min();
+        let max = spec.max();
+
+        Callback::from(move |event: Event| {
+            let value = match event.parse() {
+                Some(value) if (min..=max).contains(&value) => Some(value),
+                None if event.value().is_some_and(|value| value.is_empty()) => None,
+                _ => return,
+            };
+
+            let mut upgrade_context = current_upgrade_context.get_clone_untracked();
+            field_setter(&mut upgrade_context, value);
+            current_upgrade_context.set(upgrade_context.clone());
+            LocalStorage::set(constants::UPGRADE_CONTEXT_STORAGE_KEY, upgrade_context).unwrap();
+        })
+    }
+
     fn create_tooltip<F>(&self, field_getter: F, tooltip_fn: fn(u32) -> String) -> String
     where
         F: Fn(&UpgradeContext) -> Option<u32>,
@@ -116,30 +197,414 @@ impl UpgradeContextViewModel {
         })
     }
 
+    pub fn mvp_grade_change_callback(&self) -> Callback {
+        self.create_callback(&spec_collection::MVP_GRADE, |context, value| {
+            context.mvp_grade = value;
+        })
+    }
+
+    pub fn pc_room_change_callback(&self) -> Callback {
+        self.create_toggle_callback(|context, value| {
+            context.pc_room = value;
+        })
+    }
+
     pub fn equipment_level_change_callback(&self) -> Callback {
         self.create_callback(&spec_collection::EQUIPMENT_LEVEL, |context, value| {
             context.equipment_level = value;
         })
     }
 
+    pub fn equipment_category_change_callback(&self) -> Callback {
+        self.create_callback(&spec_collection::EQUIPMENT_CATEGORY, |context, value| {
+            context.equipment_category = value;
+        })
+    }
+
+    pub fn existing_successes_change_callback(&self) -> Callback {
+        self.create_callback(&spec_collection::EXISTING_SUCCESSES, |context, value| {
+            context.existing_successes = value;
+        })
+    }
+
     pub fn upgradeable_count_change_callback(&self) -> Callback {
         self.create_callback(&spec_collection::UPGRADEABLE_COUNT, |context, value| {
             context.upgradeable_count = value;
         })
     }
 
+    pub fn spell_trace_change_callback(&self) -> Callback {
+        self.create_callback(&spec_collection::SPELL_TRACE, |context, value| {
+            if context.spell_trace != value {
+                context.trace_required = None;
+            }
+
+            context.spell_trace = value;
+        })
+    }
+
     pub fn trace_required_change_callback(&self) -> Callback {
-        self.create_callback(&spec_collection::TRACE_REQUIRED, |context, value| {
+        self.create_optional_callback(&spec_collection::TRACE_REQUIRED, |context, value| {
             context.trace_required = value;
         })
     }
 
+    pub fn trace_limit_change_callback(&self) -> Callback {
+        self.create_callback(&spec_collection::TRACE_LIMIT, |context, value| {
+            context.trace_limit = value;
+        })
+    }
+
+    pub fn target_stat_kind_change_callback(&self) -> Callback {
+        self.create_callback(&spec_collection::TARGET_STAT_KIND, |context, value| {
+            context.target_stat_kind = value;
+        })
+    }
+
+    pub fn target_stat_change_callback(&self) -> Callback {
+        self.create_callback(&spec_collection::TARGET_STAT, |context, value| {
+            context.target_stat = value;
+        })
+    }
+
+    pub fn clean_slate_success_rate_change_callback(&self) -> Callback {
+        self.create_callback(&spec_collection::CLEAN_SLATE_SUCCESS_RATE, |context, value| {
+            context.clean_slate_success_rate = value;
+        })
+    }
+
+    pub fn clean_slate_price_change_callback(&self) -> Callback {
+        self.create_callback(&spec_collection::CLEAN_SLATE_PRICE, |context, value| {
+            context.clean_slate_price = value;
+        })
+    }
+
+    pub fn innocent_success_rate_change_callback(&self) -> Callback {
+        self.create_callback(&spec_collection::INNOCENT_SUCCESS_RATE, |context, value| {
+            context.innocent_success_rate = value;
+        })
+    }
+
+    pub fn innocent_price_change_callback(&self) -> Callback {
+        self.create_callback(&spec_collection::INNOCENT_PRICE, |context, value| {
+            context.innocent_price = value;
+        })
+    }
+
+    pub fn item_price_change_callback(&self) -> Callback {
+        self.create_callback(&spec_collection::ITEM_PRICE, |context, value| {
+            context.item_price = value;
+        })
+    }
+
+    pub fn golden_hammer_used_change_callback(&self) -> Callback {
+        self.create_toggle_callback(|context, value| {
+            context.golden_hammer_used = value;
+        })
+    }
+
+    pub fn golden_hammer_success_rate_change_callback(&self) -> Callback {
+        self.create_callback(&spec_collection::GOLDEN_HAMMER_SUCCESS_RATE, |context, value| {
+            context.golden_hammer_success_rate = value;
+        })
+    }
+
+    pub fn golden_hammer_price_change_callback(&self) -> Callback {
+        self.create_callback(&spec_collection::GOLDEN_HAMMER_PRICE, |context, value| {
+            context.golden_hammer_price = value;
+        })
+    }
+
+    pub fn chaos_scroll_change_callback(&self) -> Callback {
+        self.create_callback(&spec_collection::CHAOS_SCROLL, |context, value| {
+            context.chaos_scroll = value;
+        })
+    }
+
+    pub fn chaos_price_change_callback(&self) -> Callback {
+        self.create_callback(&spec_collection::CHAOS_PRICE, |context, value| {
+            context.chaos_price = value;
+        })
+    }
+
+    pub fn event_active_change_callback(&self) -> Callback {
+        self.create_toggle_callback(|context, value| {
+            context.event_active = value;
+        })
+    }
+
+    pub fn event_trace_discount_change_callback(&self) -> Callback {
+        self.create_callback(&spec_collection::EVENT_TRACE_DISCOUNT, |context, value| {
+            context.event_trace_discount = value;
+        })
+    }
+
+    pub fn event_success_bonus_change_callback(&self) -> Callback {
+        self.create_callback(&spec_collection::EVENT_SUCCESS_BONUS, |context, value| {
+            context.event_success_bonus = value;
+        })
+    }
+
+    pub fn event_slot_protection_change_callback(&self) -> Callback {
+        self.create_toggle_callback(|context, value| {
+            context.event_slot_protection = value;
+        })
+    }
+
+    pub fn simulation_runs_change_callback(&self) -> Callback {
+        self.create_callback(&spec_collection::SIMULATION_RUNS, |context, value| {
+            context.simulation_runs = value;
+        })
+    }
+
+    pub fn simulation_seed_change_callback(&self) -> Callback {
+        self.create_callback(&spec_collection::SIMULATION_SEED, |context, value| {
+            context.simulation_seed = value;
+        })
+    }
+
     pub fn trace_price_change_callback(&self) -> Callback {
         self.create_callback(&spec_collection::TRACE_PRICE, |context, value| {
             context.trace_price = value;
         })
     }
 
+    pub fn meso_budget_change_callback(&self) -> Callback {
+        self.create_callback(&spec_collection::MESO_BUDGET, |context, value| {
+            context.meso_budget = value;
+        })
+    }
+
+    pub fn pet_scroll_change_callback(&self) -> Callback {
+        self.create_callback(&spec_collection::PET_SCROLL, |context, value| {
+            context.pet_scroll = value;
+        })
+    }
+
+    pub fn pet_upgradeable_count_change_callback(&self) -> Callback {
+        self.create_callback(&spec_collection::PET_UPGRADEABLE_COUNT, |context, value| {
+            context.pet_upgradeable_count = value;
+        })
+    }
+
+    pub fn pet_scroll_price_change_callback(&self) -> Callback {
+        self.create_callback(&spec_collection::PET_SCROLL_PRICE, |context, value| {
+            context.pet_scroll_price = value;
+        })
+    }
+
+    pub fn cash_exchange_rate_change_callback(&self) -> Callback {
+        self.create_callback(&spec_collection::CASH_EXCHANGE_RATE, |context, value| {
+            context.cash_exchange_rate = value;
+        })
+    }
+
+    pub fn available_spell_traces(&self) -> ReadSignal<Vec<&'static SpellTrace>> {
+        let current_upgrade_context = self.current_upgrade_context;
+
+        create_memo(move || current_upgrade_context.with(equipment::available_spell_traces))
+    }
+
+    pub fn trace_required(&self) -> ReadSignal<Option<String>> {
+        let current_upgrade_context = self.current_upgrade_context;
+
+        create_selector(move || {
+            current_upgrade_context
+                .with(|context| context.trace_required.map(|value| value.to_string()))
+        })
+    }
+
+    pub fn available_stats(&self) -> ReadSignal<Vec<&'static StatKind>> {
+        let current_upgrade_context = self.current_upgrade_context;
+
+        create_memo(move || current_upgrade_context.with(equipment::available_stats))
+    }
+
+    pub fn success_rate(
+        &self,
+        spell_trace: &'static SpellTrace,
+    ) -> ReadSignal<Option<SuccessRate>> {
+        let current_upgrade_context = self.current_upgrade_context;
+
+        create_memo(move || {
+            current_upgrade_context.with(|context| {
+                success_rate::success_rate(context, spell_trace.success_rate)
+                    .filter(|_| equipment::allows(context, spell_trace))
+            })
+        })
+    }
+
+    pub fn trace_requirement(
+        &self,
+        spell_trace: &'static SpellTrace,
+    ) -> ReadSignal<Option<TraceRequirement>> {
+        let current_upgrade_context = self.current_upgrade_context;
+
+        create_memo(move || {
+            current_upgrade_context.with(|context| {
+                equipment::allows(context, spell_trace)
+                    .then(|| spell_trace::trace_requirement(context, spell_trace))
+            })
+        })
+    }
+
+    pub fn stat_outcome(
+        &self,
+        spell_trace: &'static SpellTrace,
+    ) -> ReadSignal<Option<StatOutcome>> {
+        let current_upgrade_context = self.current_upgrade_context;
+
+        create_memo(move || {
+            current_upgrade_context.with(|context| stat_outcome::stat_outcome(context, spell_trace))
+        })
+    }
+
+    pub fn selected_stat_outcome(&self) -> ReadSignal<Option<StatOutcome>> {
+        let current_upgrade_context = self.current_upgrade_context;
+
+        create_memo(move || {
+            current_upgrade_context.with(|context| {
+                stat_outcome::stat_outcome(context, spell_trace::selected(context)?)
+            })
+        })
+    }
+
+    pub fn within_trace_limit(&self) -> ReadSignal<Option<f64>> {
+        let current_upgrade_context = self.current_upgrade_context;
+        let cost_distribution = self.cost_distribution;
+
+        create_memo(move || {
+            let trace_limit = current_upgrade_context.with(|context| context.trace_limit)?;
+            cost_distribution.with(|distribution| {
+                distribution.as_ref().map(|distribution| distribution.within_traces(trace_limit))
+            })
+        })
+    }
+
+    pub fn salvation_comparison(&self) -> ReadSignal<Option<[SalvationOutcome; 2]>> {
+        let current_upgrade_context = self.current_upgrade_context;
+
+        create_memo(move || current_upgrade_context.with(upgrade_cost::salvation_comparison))
+    }
+
+    pub fn reset_plan(&self) -> ReadSignal<Option<ResetPlan>> {
+        let current_upgrade_context = self.current_upgrade_context;
+
+        create_memo(move || current_upgrade_context.with(innocent::reset_plan))
+    }
+
+    pub fn hammer_slot(&self) -> ReadSignal<Option<HammerSlot>> {
+        let current_upgrade_context = self.current_upgrade_context;
+
+        create_memo(move || current_upgrade_context.with(golden_hammer::hammer_slot))
+    }
+
+    pub fn event_comparison(&self) -> ReadSignal<Option<EventComparison>> {
+        let current_upgrade_context = self.current_upgrade_context;
+
+        create_memo(move || current_upgrade_context.with(event::event_comparison))
+    }
+
+    pub fn chaos_outcome(&self) -> ReadSignal<Option<ChaosOutcome>> {
+        let current_upgrade_context = self.current_upgrade_context;
+
+        create_memo(move || current_upgrade_context.with(chaos::chaos_outcome))
+    }
+
+    pub fn target_plans(&self) -> ReadSignal<Option<Vec<TargetPlan>>> {
+        let current_upgrade_context = self.current_upgrade_context;
+        let strategy = self.strategy;
+
+        create_memo(move || {
+            current_upgrade_context.with(|context| {
+                strategy.with(|strategy| target_stat::target_plans(context, strategy.as_ref()))
+            })
+        })
+    }
+
+    pub fn rebuild_meso(&self) -> ReadSignal<Option<f64>> {
+        let expected_cost = self.expected_cost;
+        let strategy = self.strategy;
+
+        create_selector(move || {
+            strategy
+                .with(|strategy| strategy.as_ref().map(|strategy| strategy.expected_meso))
+                .or_else(|| expected_cost.with(|cost| cost.as_ref().map(|cost| cost.meso)))
+        })
+    }
+
+    pub fn sensitivity_toggle_callback(&self) -> Callback {
+        let sensitivity_shown = self.sensitivity_shown;
+
+        Callback::from(move |_event: Event| {
+            sensitivity_shown.set(!sensitivity_shown.get_untracked());
+        })
+    }
+
+    pub fn sensitivity(&self) -> ReadSignal<Option<Vec<SensitivityRow>>> {
+        let current_upgrade_context = self.current_upgrade_context;
+        let sensitivity_shown = self.sensitivity_shown;
+
+        create_memo(move || {
+            if !sensitivity_shown.get() {
+                return None;
+            }
+
+            current_upgrade_context.with(sensitivity::sensitivity)
+        })
+    }
+
+    pub fn budget_plan(&self) -> ReadSignal<Option<BudgetPlan>> {
+        let current_upgrade_context = self.current_upgrade_context;
+
+        create_memo(move || current_upgrade_context.with(budget::budget_plan))
+    }
+
+    pub fn pet_outcome(&self) -> ReadSignal<Option<PetOutcome>> {
+        let current_upgrade_context = self.current_upgrade_context;
+
+        create_memo(move || current_upgrade_context.with(pet_equipment::pet_outcome))
+    }
+
+    pub fn simulation_toggle_callback(&self) -> Callback {
+        let simulation_shown = self.simulation_shown;
+
+        Callback::from(move |_event: Event| {
+            simulation_shown.set(!simulation_shown.get_untracked());
+        })
+    }
+
+    pub fn upgrade_simulation(&self) -> ReadSignal<Option<Simulation>> {
+        let current_upgrade_context = self.current_upgrade_context;
+        let simulation_shown = self.simulation_shown;
+
+        create_memo(move || {
+            if !simulation_shown.get() {
+                return None;
+            }
+
+            current_upgrade_context.with(simulation::simulate_upgrade)
+        })
+    }
+
+    pub fn strategy_simulation(&self) -> ReadSignal<Option<StrategySimulation>> {
+        let current_upgrade_context = self.current_upgrade_context;
+        let strategy = self.strategy;
+        let simulation_shown = self.simulation_shown;
+
+        create_memo(move || {
+            if !simulation_shown.get() {
+                return None;
+            }
+
+            strategy.with(|strategy| {
+                let strategy = strategy.as_ref()?;
+                current_upgrade_context
+                    .with(|context| simulation::simulate_strategy(context, strategy))
+            })
+        })
+    }
+
     pub fn handicraft_tooltip(&self) -> String {
         self.create_tooltip(|context| context.handicraft, upgrade_context::handicraft_tooltip)
     }
@@ -161,4 +626,65 @@ impl UpgradeContextViewModel {
     pub fn trace_price_tooltip(&self) -> String {
         self.create_tooltip(|context| context.trace_price, upgrade_context::trace_price_tooltip)
     }
+
+    pub fn clean_slate_price_tooltip(&self) -> String {
+        self.create_tooltip(
+            |context| context.clean_slate_price,
+            upgrade_context::clean_slate_price_tooltip,
+        )
+    }
+
+    pub fn innocent_price_tooltip(&self) -> String {
+        self.create_tooltip(
+            |context| context.innocent_price,
+            upgrade_context::innocent_price_tooltip,
+        )
+    }
+
+    pub fn item_price_tooltip(&self) -> String {
+        self.create_tooltip(|context| context.item_price, upgrade_context::item_price_tooltip)
+    }
+
+    pub fn golden_hammer_price_tooltip(&self) -> String {
+        self.create_tooltip(
+            |context| context.golden_hammer_price,
+            upgrade_context::golden_hammer_price_tooltip,
+        )
+    }
+
+    pub fn event_trace_discount_tooltip(&self) -> String {
+        self.create_tooltip(
+            |context| context.event_trace_discount,
+            upgrade_context::event_trace_discount_tooltip,
+        )
+    }
+
+    pub fn event_success_bonus_tooltip(&self) -> String {
+        self.create_tooltip(
+            |context| context.event_success_bonus,
+            upgrade_context::event_success_bonus_tooltip,
+        )
+    }
+
+    pub fn chaos_price_tooltip(&self) -> String {
+        self.create_tooltip(|context| context.chaos_price, upgrade_context::chaos_price_tooltip)
+    }
+
+    pub fn pet_scroll_price_tooltip(&self) -> String {
+        self.create_tooltip(
+            |context| context.pet_scroll_price,
+            upgrade_context::pet_scroll_price_tooltip,
+        )
+    }
+
+    pub fn cash_exchange_rate_tooltip(&self) -> String {
+        self.create_tooltip(
+            |context| context.cash_exchange_rate,
+            upgrade_context::cash_exchange_rate_tooltip,
+        )
+    }
+
+    pub fn meso_budget_tooltip(&self) -> String {
+        self.create_tooltip(|context| context.meso_budget, upgrade_context::meso_budget_tooltip)
+    }
 }
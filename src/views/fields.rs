@@ -0,0 +1,123 @@
+use crate::utils::sycamore::Callback;
+use crate::view_models::upgrade_context_view_model::Spec;
+use sycamore::prelude::*;
+
+mod constants {
+    pub const EMPTY_RESULT: &str = "-";
+}
+
+pub fn fieldset(legend: &'static str, fields: Vec<View>) -> View {
+    view! {
+        fieldset(class="fieldset bg-base-200 border-base-300 rounded-box w-xs border p-4") {
+            legend(class="fieldset-legend") { (legend) }
+            (fields)
+        }
+    }
+}
+
+pub fn field(spec: &Spec, value: Option<String>, callback: Callback) -> View {
+    let label = spec.label;
+    let placeholder = spec.placeholder();
+    let min = spec.min().to_string();
+    let max = spec.max().to_string();
+
+    view! {
+        label(class="label", r#for=label) { (label) }
+        input(
+            r#type="number",
+            id=label,
+            class="input validator",
+            required=true,
+            placeholder=placeholder,
+            value=value,
+            min=min,
+            max=max,
+            on:change=callback
+        ) {}
+    }
+}
+
+pub fn select_field<F>(spec: &Spec, value: Option<String>, options: F, callback: Callback) -> View
+where
+    F: Fn() -> Vec<(String, String)> + 'static,
+{
+    labeled_select(spec.label.to_owned(), Some(spec.placeholder()), value, options, callback)
+}
+
+pub fn labeled_select<F>(
+    label: String,
+    placeholder: Option<String>,
+    value: Option<String>,
+    options: F,
+    callback: Callback,
+) -> View
+where
+    F: Fn() -> Vec<(String, String)> + 'static,
+{
+    let id = label.clone();
+    let target = label.clone();
+    let unselected = value.is_none();
+    let placeholder = placeholder.map(|placeholder| {
+        view! { option(disabled=true, selected=unselected) { (placeholder) } }
+    });
+    let options = move || {
+        options()
+            .into_iter()
+            .map(|(option_value, option_label)| {
+                select_option(option_value, option_label, value.as_deref())
+            })
+            .collect::<Vec<View>>()
+    };
+
+    view! {
+        label(class="label", r#for=target) { (label) }
+        select(id=id, class="select", on:change=callback) {
+            (placeholder)
+            (options)
+        }
+    }
+}
+
+fn select_option(value: String, label: String, selected_value: Option<&str>) -> View {
+    let selected = selected_value == Some(value.as_str());
+
+    view! {
+        option(value=value, selected=selected) { (label) }
+    }
+}
+
+pub fn toggle_field(
+    spec: &Spec,
+    labels: [&'static str; 2],
+    value: Option<bool>,
+    callback: Callback,
+) -> View {
+    select_field(spec, value.map(|value| value.to_string()), toggle_options(labels), callback)
+}
+
+pub fn toggle_options(labels: [&'static str; 2]) -> impl Fn() -> Vec<(String, String)> {
+    let [off_label, on_label] = labels;
+
+    move || vec![(false.to_string(), off_label.to_owned()), (true.to_string(), on_label.to_owned())]
+}
+
+pub fn result<L, F>(label: L, value: F) -> View
+where
+    L: Into<View>,
+    F: Fn() -> Option<String> + 'static,
+{
+    view! {
+        label(class="label") { (label) }
+        p(class="text-lg font-semibold") {
+            (move || value().unwrap_or_else(|| constants::EMPTY_RESULT.to_owned()))
+        }
+    }
+}
+
+pub fn memo_result<T>(
+    label: &'static str,
+    memo: ReadSignal<Option<T>>,
+    text_fn: fn(&T) -> String,
+) -> View {
+    result(label, move || memo.with(|value| value.as_ref().map(text_fn)))
+}
@@ -0,0 +1,59 @@
+use crate::view_models::game_data_view_model::GameDataViewModel;
+use sycamore::prelude::*;
+
+mod constants {
+    pub const GAME_DATA_LEGEND: &str = "게임 데이터";
+    pub const VERSION_LABEL: &str = "데이터 버전";
+    pub const OVERRIDDEN_LABEL: &str = "불러온 파일 사용 중";
+    pub const EMBEDDED_LABEL: &str = "내장 데이터 사용 중";
+    pub const FILE_LABEL: &str = "새 데이터 파일 불러오기";
+    pub const RESET_LABEL: &str = "내장 데이터로 되돌리기";
+}
+
+#[component]
+pub fn GameDataView() -> View {
+    view! { GameDataFieldset() }
+}
+
+#[component]
+fn GameDataFieldset() -> View {
+    let view_model = use_context::<GameDataViewModel>();
+    let version = view_model.version.clone();
+    let source = if view_model.overridden {
+        constants::OVERRIDDEN_LABEL
+    } else {
+        constants::EMBEDDED_LABEL
+    };
+    let error = view_model.error;
+    let file_callback = view_model.file_change_callback();
+    let reset_callback = GameDataViewModel::reset_callback();
+
+    view! {
+        div(class="p-16 pb-0") {
+            fieldset(class="fieldset bg-base-200 border-base-300 rounded-box w-xs border p-4") {
+                legend(class="fieldset-legend") { (constants::GAME_DATA_LEGEND) }
+                label(class="label") { (constants::VERSION_LABEL) }
+                p(class="text-lg font-semibold") { (version) }
+                p(class="label") { (source) }
+                label(class="label", r#for=constants::FILE_LABEL) { (constants::FILE_LABEL) }
+                input(
+                    r#type="file",
+                    id=constants::FILE_LABEL,
+                    class="file-input",
+                    accept="application/json",
+                    on:change=file_callback
+                ) {}
+                (move || error.with(|error| match error {
+                    Some(message) => {
+                        let message = message.clone();
+                        view! { p(class="label text-error") { (message) } }
+                    }
+                    None => view! {},
+                }))
+                button(class="btn btn-sm", disabled=!view_model.stored, on:click=reset_callback) {
+                    (constants::RESET_LABEL)
+                }
+            }
+        }
+    }
+}
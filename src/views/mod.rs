@@ -1,2 +1,5 @@
+pub mod fields;
+pub mod game_data_view;
+pub mod starforce_view;
 pub mod theme_view;
 pub mod upgrade_context_view;
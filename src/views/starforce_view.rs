@@ -0,0 +1,327 @@
+use crate::utils::sycamore::{Callback, ViewVecExt};
+use crate::view_models::starforce_view_model::{
+    EventOutcome, MembershipOutcome, OptionOutcome, PERCENTILES, StarforceCost, StarforceViewModel,
+    spec_collection, starforce, starforce_event, starforce_membership, upgrade_cost,
+};
+use crate::view_models::upgrade_context_view_model::UpgradeContextViewModel;
+use crate::views::fields::{
+    field, fieldset, labeled_select, memo_result, result, select_field, toggle_field,
+    toggle_options,
+};
+use sycamore::prelude::*;
+
+mod constants {
+    pub const STARFORCE_LEGEND: &str = "스타포스 정보";
+    pub const MAX_STAR_LABEL: &str = "장비 레벨 기준 최대 스타포스";
+    pub const COST_LEGEND: &str = "스타포스 기대 비용";
+    pub const ATTEMPTS_LABEL: &str = "스타포스 시도 횟수";
+    pub const DESTRUCTIONS_LABEL: &str = "파괴 횟수";
+    pub const RECOVERY_COST_LABEL: &str = "기대 복구 비용";
+    pub const MESO_LABEL: &str = "메소 소모량";
+    pub const DISTRIBUTION_LEGEND: &str = "스타포스 비용 분포";
+    pub const NO_DESTRUCTION_LABEL: &str = "파괴 없이 달성할 확률";
+    pub const RECOVERY_LEGEND: &str = "파괴 시 복구";
+    pub const RECOVERY_MESO_LABEL: &str = "파괴 1회당 복구 비용";
+    pub const SAFEGUARD_LEGEND: &str = "파괴방지";
+    pub const SAFEGUARD_POLICY_LABEL: &str = "최적 파괴방지 구간";
+    pub const SAFEGUARD_MESO_LABEL: &str = "최적 구간 기대 메소";
+    pub const SAFEGUARD_DESTRUCTIONS_LABEL: &str = "최적 구간 파괴 횟수";
+    pub const COMPARISON_LEGEND: &str = "스타캐치·파괴방지 비교";
+    pub const COMPARISON_OPTION_HEADER: &str = "조합";
+    pub const COMPARISON_MESO_HEADER: &str = "기대 메소";
+    pub const COMPARISON_DESTRUCTIONS_HEADER: &str = "파괴 횟수";
+    pub const EVENT_COMPARISON_LEGEND: &str = "스타포스 이벤트 비교";
+    pub const EVENT_HEADER: &str = "이벤트";
+    pub const MEMBERSHIP_COMPARISON_LEGEND: &str = "MVP·PC방 할인 비교";
+    pub const MEMBERSHIP_HEADER: &str = "등급";
+    pub const INACTIVE_LABEL: &str = "미적용";
+    pub const ACTIVE_LABEL: &str = "적용";
+}
+
+#[component]
+pub fn StarforceView() -> View {
+    let upgrade_context_view_model = use_context::<UpgradeContextViewModel>();
+    let view_model = StarforceViewModel::new(
+        upgrade_context_view_model.current_upgrade_context,
+        upgrade_context_view_model.rebuild_meso(),
+    );
+    provide_context(view_model);
+
+    view! { Fieldsets() }
+}
+
+#[component]
+fn Fieldsets() -> View {
+    view! {
+        div(class="grid grid-cols-6 gap-48 p-16 pt-0") {
+            (fieldset(constants::STARFORCE_LEGEND, starforce_fields()))
+            (fieldset(constants::RECOVERY_LEGEND, recovery_fields()))
+            (fieldset(constants::COST_LEGEND, cost_fields()))
+            (fieldset(constants::DISTRIBUTION_LEGEND, distribution_fields()))
+            (fieldset(constants::SAFEGUARD_LEGEND, safeguard_fields()))
+            (fieldset(constants::COMPARISON_LEGEND, comparison_fields()))
+            (fieldset(constants::EVENT_COMPARISON_LEGEND, event_comparison_fields()))
+            (fieldset(constants::MEMBERSHIP_COMPARISON_LEGEND, membership_comparison_fields()))
+        }
+    }
+}
+
+fn starforce_fields() -> Vec<View> {
+    let view_model = use_context::<StarforceViewModel>();
+    let equipment_level = view_model.equipment_level;
+
+    let current_star = view_model.get_field(|context| context.current_star);
+    let target_star = view_model.get_field(|context| context.target_star);
+    let star_catch = view_model.get_toggle(|context| context.star_catch);
+    let starforce_event = view_model.get_field(|context| context.starforce_event);
+
+    let current_star_callback = view_model.current_star_change_callback();
+    let target_star_callback = view_model.target_star_change_callback();
+    let star_catch_callback = view_model.star_catch_change_callback();
+    let starforce_event_callback = view_model.starforce_event_change_callback();
+
+    [
+        memo_result(constants::MAX_STAR_LABEL, equipment_level, |equipment_level| {
+            starforce::max_star_text(*equipment_level)
+        }),
+        field(&spec_collection::CURRENT_STAR, current_star, current_star_callback),
+        field(&spec_collection::TARGET_STAR, target_star, target_star_callback),
+        toggle_field(
+            &spec_collection::STAR_CATCH,
+            [constants::INACTIVE_LABEL, constants::ACTIVE_LABEL],
+            star_catch,
+            star_catch_callback,
+        ),
+        select_field(
+            &spec_collection::STARFORCE_EVENT,
+            starforce_event,
+            || {
+                starforce_event::starforce_events()
+                    .iter()
+                    .map(|event| (event.id.to_string(), event.label.clone()))
+                    .collect()
+            },
+            starforce_event_callback,
+        ),
+    ]
+    .into_iter()
+    .collect::<Vec<View>>()
+    .join(|| view! { div(class="divider") })
+}
+
+fn recovery_fields() -> Vec<View> {
+    let view_model = use_context::<StarforceViewModel>();
+    let recovery_meso = view_model.recovery_meso;
+
+    let recovery_method = view_model.get_field(|context| context.recovery_method);
+    let spare_price = view_model.get_field(|context| context.spare_price);
+    let restoration_cost = view_model.get_field(|context| context.restoration_cost);
+
+    let recovery_method_callback = view_model.recovery_method_change_callback();
+    let spare_price_callback = view_model.spare_price_change_callback();
+    let restoration_cost_callback = view_model.restoration_cost_change_callback();
+
+    let spare_price_tooltip = view_model.spare_price_tooltip();
+    let restoration_cost_tooltip = view_model.restoration_cost_tooltip();
+
+    [
+        select_field(
+            &spec_collection::RECOVERY_METHOD,
+            recovery_method,
+            || {
+                starforce::recovery_methods()
+                    .into_iter()
+                    .map(|(method, label)| (method.to_string(), label.to_owned()))
+                    .collect()
+            },
+            recovery_method_callback,
+        ),
+        view! {
+            (field(&spec_collection::SPARE_PRICE, spare_price.clone(), spare_price_callback.clone()))
+            (spare_price_tooltip)
+        },
+        view! {
+            (field(&spec_collection::RESTORATION_COST, restoration_cost.clone(), restoration_cost_callback.clone()))
+            (restoration_cost_tooltip)
+        },
+        memo_result(constants::RECOVERY_MESO_LABEL, recovery_meso, |meso| {
+            upgrade_cost::meso_text(*meso)
+        }),
+    ]
+    .into_iter()
+    .collect::<Vec<View>>()
+    .join(|| view! { div(class="divider") })
+}
+
+fn cost_fields() -> Vec<View> {
+    let view_model = use_context::<StarforceViewModel>();
+    let expected_cost = view_model.expected_cost;
+
+    [
+        memo_result(constants::ATTEMPTS_LABEL, expected_cost, |cost| {
+            upgrade_cost::attempts_text(cost.attempts)
+        }),
+        memo_result(constants::DESTRUCTIONS_LABEL, expected_cost, |cost| {
+            starforce::destructions_text(cost.destructions)
+        }),
+        memo_result(constants::RECOVERY_COST_LABEL, expected_cost, |cost| {
+            upgrade_cost::meso_text(cost.recovery)
+        }),
+        memo_result(constants::MESO_LABEL, expected_cost, |cost| {
+            upgrade_cost::meso_text(cost.meso)
+        }),
+    ]
+    .into_iter()
+    .collect::<Vec<View>>()
+    .join(|| view! { div(class="divider") })
+}
+
+fn distribution_fields() -> Vec<View> {
+    let view_model = use_context::<StarforceViewModel>();
+    let cost_distribution = view_model.cost_distribution;
+
+    PERCENTILES
+        .into_iter()
+        .map(|percentile| {
+            result(upgrade_cost::percentile_label(percentile), move || {
+                cost_distribution.with(|distribution| {
+                    distribution.as_ref().map(|distribution| {
+                        upgrade_cost::meso_text(distribution.meso_percentile(percentile))
+                    })
+                })
+            })
+        })
+        .chain([memo_result(constants::NO_DESTRUCTION_LABEL, cost_distribution, |distribution| {
+            upgrade_cost::probability_text(distribution.no_destruction())
+        })])
+        .collect::<Vec<View>>()
+        .join(|| view! { div(class="divider") })
+}
+
+fn safeguard_fields() -> Vec<View> {
+    let view_model = use_context::<StarforceViewModel>();
+    let safeguard_policy = view_model.safeguard_policy();
+
+    starforce::safeguard_stars()
+        .map(|star| {
+            safeguard_field(
+                star,
+                view_model.safeguard_value(star),
+                view_model.safeguard_change_callback(star),
+            )
+        })
+        .chain([
+            memo_result(
+                constants::SAFEGUARD_POLICY_LABEL,
+                safeguard_policy,
+                starforce::safeguard_text,
+            ),
+            memo_result(constants::SAFEGUARD_MESO_LABEL, safeguard_policy, |policy| {
+                upgrade_cost::meso_text(policy.cost.meso)
+            }),
+            memo_result(constants::SAFEGUARD_DESTRUCTIONS_LABEL, safeguard_policy, |policy| {
+                starforce::destructions_text(policy.cost.destructions)
+            }),
+        ])
+        .collect::<Vec<View>>()
+        .join(|| view! { div(class="divider") })
+}
+
+fn comparison_fields() -> Vec<View> {
+    let view_model = use_context::<StarforceViewModel>();
+    let option_comparison = view_model.option_comparison();
+
+    vec![view! {
+        (move || option_comparison.with(|outcomes| {
+            outcomes.as_deref().map(option_table).unwrap_or_default()
+        }))
+    }]
+}
+
+fn option_table(outcomes: &[OptionOutcome]) -> View {
+    comparison_table(
+        constants::COMPARISON_OPTION_HEADER,
+        outcomes.iter().map(|outcome| (starforce::option_label(outcome), &outcome.cost)),
+    )
+}
+
+fn event_comparison_fields() -> Vec<View> {
+    let view_model = use_context::<StarforceViewModel>();
+    let event_comparison = view_model.event_comparison();
+
+    vec![view! {
+        (move || event_comparison.with(|outcomes| {
+            outcomes.as_deref().map(event_table).unwrap_or_default()
+        }))
+    }]
+}
+
+fn event_table(outcomes: &[EventOutcome]) -> View {
+    comparison_table(
+        constants::EVENT_HEADER,
+        outcomes.iter().map(|outcome| (outcome.label.to_owned(), &outcome.cost)),
+    )
+}
+
+fn membership_comparison_fields() -> Vec<View> {
+    let view_model = use_context::<StarforceViewModel>();
+    let membership_comparison = view_model.membership_comparison();
+
+    vec![view! {
+        (move || membership_comparison.with(|outcomes| {
+            outcomes.as_deref().map(membership_table).unwrap_or_default()
+        }))
+    }]
+}
+
+fn membership_table(outcomes: &[MembershipOutcome]) -> View {
+    comparison_table(
+        constants::MEMBERSHIP_HEADER,
+        outcomes
+            .iter()
+            .map(|outcome| (starforce_membership::membership_label(outcome), &outcome.cost)),
+    )
+}
+
+fn safeguard_field(star: u32, value: bool, callback: Callback) -> View {
+    labeled_select(
+        starforce::safeguard_label(star),
+        None,
+        Some(value.to_string()),
+        toggle_options([constants::INACTIVE_LABEL, constants::ACTIVE_LABEL]),
+        callback,
+    )
+}
+
+fn comparison_table<'a, I>(header: &'static str, rows: I) -> View
+where
+    I: Iterator<Item = (String, &'a StarforceCost)>,
+{
+    let rows = rows.map(|(label, cost)| comparison_row(label, cost)).collect::<Vec<View>>();
+
+    view! {
+        table(class="table table-xs") {
+            thead {
+                tr {
+                    th { (header) }
+                    th { (constants::COMPARISON_MESO_HEADER) }
+                    th { (constants::COMPARISON_DESTRUCTIONS_HEADER) }
+                }
+            }
+            tbody { (rows) }
+        }
+    }
+}
+
+fn comparison_row(label: String, cost: &StarforceCost) -> View {
+    let meso = upgrade_cost::meso_text(cost.meso);
+    let destructions = starforce::destructions_text(cost.destructions);
+
+    view! {
+        tr {
+            th { (label) }
+            td { (meso) }
+            td { (destructions) }
+        }
+    }
+}
@@ -1,20 +1,97 @@
-use crate::utils::sycamore::{Callback, ViewVecExt};
+use crate::utils::sycamore::ViewVecExt;
 use crate::view_models::upgrade_context_view_model::{
-    Spec, UpgradeContextViewModel, spec_collection,
+    ChaosOutcome, Distribution, EQUIPMENT_CATEGORIES, PERCENTILES, PolicyRow, SensitivityRow,
+    TargetPlan, UpgradeContextViewModel, budget, chaos, event, golden_hammer, innocent,
+    pet_equipment, sensitivity, simulation, spec_collection, spell_trace, starforce_membership,
+    stat_outcome, strategy, success_rate, upgrade_cost,
 };
+use crate::views::fields::{field, fieldset, memo_result, result, select_field, toggle_field};
 use sycamore::prelude::*;
 
 mod constants {
     pub const POTENTIAL_LEGEND: &str = "확률 정보";
+    pub const SENSITIVITY_LEGEND: &str = "길드 스킬 레벨별 절약 메소";
+    pub const SENSITIVITY_LEVEL_HEADER: &str = "레벨";
+    pub const SENSITIVITY_MESO_HEADER: &str = "기대 메소";
+    pub const SENSITIVITY_SHOW_LABEL: &str = "계산하기";
+    pub const SENSITIVITY_HIDE_LABEL: &str = "숨기기";
     pub const EQUIPMENT_LEGEND: &str = "장비 정보";
     pub const PRICE_LEGEND: &str = "시세 정보";
+    pub const SUPPORT_SCROLL_LEGEND: &str = "보조 주문서 정보";
+    pub const SUCCESS_RATE_LEGEND: &str = "주문서별 성공 확률";
+    pub const TRACE_REQUIRED_LEGEND: &str = "주문서별 흔적 필요 갯수";
+    pub const STAT_OUTCOME_LEGEND: &str = "주문서별 최종 스탯";
+    pub const SELECTED_STAT_LABEL: &str = "선택한 주문서 최종 스탯 분포";
+    pub const COST_LEGEND: &str = "기대 비용";
+    pub const ATTEMPTS_LABEL: &str = "주문서 시도 횟수";
+    pub const SUCCESSES_LABEL: &str = "성공 횟수";
+    pub const TRACES_LABEL: &str = "주문의 흔적 소모량";
+    pub const MESO_LABEL: &str = "메소 소모량";
+    pub const DISTRIBUTION_LEGEND: &str = "비용 분포";
+    pub const WITHIN_TRACE_LIMIT_LABEL: &str = "한도 이내 완료 확률";
+    pub const STRATEGY_LEGEND: &str = "최적 주문서 전략";
+    pub const STRATEGY_MESO_LABEL: &str = "목표 달성까지 기대 메소";
+    pub const ITEM_SUCCESS_LABEL: &str = "장비 1개당 목표 달성 확률";
+    pub const CLEAN_SLATES_LABEL: &str = "기대 순백의 주문서 사용량";
+    pub const INNOCENTS_LABEL: &str = "기대 이노센트 주문서 사용량";
+    pub const RESET_LEGEND: &str = "이노센트 초기화 기준";
+    pub const RESET_THRESHOLD_LABEL: &str = "초기화 기준 (목표 수치)";
+    pub const KEEP_PROBABILITY_LABEL: &str = "1회 강화 후 유지 확률";
+    pub const RESET_MESO_LABEL: &str = "선택한 주문서로 목표 달성까지 기대 메소";
+    pub const FINAL_STAT_MEAN_LABEL: &str = "기대 최종 스탯";
+    pub const FINAL_STAT_LABEL: &str = "최종 스탯 분포";
+    pub const SIMULATION_LEGEND: &str = "시뮬레이션";
+    pub const SIMULATION_SHOW_LABEL: &str = "실행하기";
+    pub const SIMULATION_HIDE_LABEL: &str = "숨기기";
+    pub const SIMULATION_MEAN_LABEL: &str = "평균 비용";
+    pub const STRATEGY_SIMULATION_LABEL: &str = "최적 전략 평균 메소";
+    pub const TARGET_LEGEND: &str = "목표 스탯 달성";
+    pub const TARGET_STRATEGY_HEADER: &str = "전략";
+    pub const TARGET_PROBABILITY_HEADER: &str = "달성 확률";
+    pub const TARGET_MESO_HEADER: &str = "성공 장비 1개당 메소";
+    pub const BUDGET_LEGEND: &str = "예산 내 목표 달성";
+    pub const BUDGET_PROBABILITY_LABEL: &str = "예산 내 최고 달성 확률";
+    pub const BUDGET_POLICY_LABEL: &str = "입력 예산 기준 최고 확률 전략";
+    pub const SALVATION_LEGEND: &str = "실패를 두려워 않는 효과";
+    pub const SALVATION_SUCCESSES_LABEL: &str = "기대 성공 횟수";
+    pub const SALVATION_TRACES_LABEL: &str = "목표 달성 기대 흔적";
+    pub const SALVATION_MESO_LABEL: &str = "목표 달성 기대 메소";
+    pub const HAMMER_LEGEND: &str = "황금망치";
+    pub const PC_ROOM_INACTIVE_LABEL: &str = "미접속";
+    pub const PC_ROOM_ACTIVE_LABEL: &str = "접속";
+    pub const HAMMER_UNUSED_LABEL: &str = "미사용";
+    pub const HAMMER_USED_LABEL: &str = "사용함";
+    pub const HAMMERS_LABEL: &str = "기대 황금망치 사용량";
+    pub const HAMMER_STAT_LABEL: &str = "추가 칸 기대 스탯";
+    pub const HAMMER_MESO_LABEL: &str = "추가 칸 기대 메소";
+    pub const HAMMER_MESO_PER_STAT_LABEL: &str = "추가 칸 스탯 1당 메소";
+    pub const HAMMER_TARGET_MESO_LABEL: &str = "목표 달성 기대 메소";
+    pub const EVENT_LEGEND: &str = "이벤트 정보";
+    pub const EVENT_COMPARISON_LEGEND: &str = "이벤트 비교";
+    pub const EVENT_INACTIVE_LABEL: &str = "미적용";
+    pub const EVENT_ACTIVE_LABEL: &str = "적용";
+    pub const EVENT_TRACES_LABEL: &str = "기대 흔적 소모량";
+    pub const EVENT_MESO_LABEL: &str = "기대 메소";
+    pub const EVENT_SAVINGS_LABEL: &str = "절약 메소";
+    pub const EVENT_TARGET_MESO_LABEL: &str = "목표 달성 기대 메소";
+    pub const EVENT_TARGET_SAVINGS_LABEL: &str = "목표 달성 절약 메소";
+    pub const CHAOS_LEGEND: &str = "혼돈의 주문서";
+    pub const CHAOS_ATTACK_MEAN_LABEL: &str = "기대 공격력/마력 변화";
+    pub const CHAOS_ALL_STATS_MEAN_LABEL: &str = "기대 올스탯 변화 합계";
+    pub const CHAOS_PASS_MESO_LABEL: &str = "1회 강화 기대 메소";
+    pub const CHAOS_TARGET_PROBABILITY_LABEL: &str = "1회 강화 후 목표 달성 확률";
+    pub const CHAOS_TARGET_MESO_LABEL: &str = "목표 달성 기대 메소";
+    pub const CHAOS_ATTACK_LABEL: &str = "공격력/마력 변화 분포";
+    pub const PET_LEGEND: &str = "펫장비";
+    pub const PET_ATTACK_LABEL: &str = "펫 3마리 기대 공격력 합계";
+    pub const PET_ATTEMPTS_LABEL: &str = "기대 주문서 사용량";
+    pub const PET_MESO_LABEL: &str = "기대 메소";
+    pub const PET_ATTACK_DISTRIBUTION_LABEL: &str = "공격력 합계 분포";
+    pub const EMPTY_RESULT: &str = "-";
 }
 
 #[component]
 pub fn UpgradeContextView() -> View {
-    let view_model = UpgradeContextViewModel::new();
-    provide_context(view_model);
-
     view! { Fieldsets() }
 }
 
@@ -23,17 +100,26 @@ fn Fieldsets() -> View {
     view! {
         div(class="grid grid-cols-6 gap-48 p-16") {
             (fieldset(constants::POTENTIAL_LEGEND, probability_fields()))
+            (fieldset(constants::SENSITIVITY_LEGEND, sensitivity_fields()))
             (fieldset(constants::EQUIPMENT_LEGEND, equipment_fields()))
             (fieldset(constants::PRICE_LEGEND, price_fields()))
-        }
-    }
-}
-
-fn fieldset(legend: &'static str, fields: Vec<View>) -> View {
-    view! {
-        fieldset(class="fieldset bg-base-200 border-base-300 rounded-box w-xs border p-4") {
-            legend(class="fieldset-legend") { (legend) }
-            (fields)
+            (fieldset(constants::SUPPORT_SCROLL_LEGEND, support_scroll_fields()))
+            (fieldset(constants::EVENT_LEGEND, event_fields()))
+            (fieldset(constants::SUCCESS_RATE_LEGEND, success_rate_fields()))
+            (fieldset(constants::TRACE_REQUIRED_LEGEND, trace_required_fields()))
+            (fieldset(constants::STAT_OUTCOME_LEGEND, stat_outcome_fields()))
+            (fieldset(constants::COST_LEGEND, cost_fields()))
+            (fieldset(constants::DISTRIBUTION_LEGEND, distribution_fields()))
+            (fieldset(constants::STRATEGY_LEGEND, strategy_fields()))
+            (fieldset(constants::TARGET_LEGEND, target_fields()))
+            (fieldset(constants::BUDGET_LEGEND, budget_fields()))
+            (fieldset(constants::SALVATION_LEGEND, salvation_fields()))
+            (fieldset(constants::EVENT_COMPARISON_LEGEND, event_comparison_fields()))
+            (fieldset(constants::HAMMER_LEGEND, hammer_fields()))
+            (fieldset(constants::CHAOS_LEGEND, chaos_fields()))
+            (fieldset(constants::PET_LEGEND, pet_fields()))
+            (fieldset(constants::RESET_LEGEND, reset_fields()))
+            (fieldset(constants::SIMULATION_LEGEND, simulation_fields()))
         }
     }
 }
@@ -44,10 +130,14 @@ fn probability_fields() -> Vec<View> {
     let handicraft = view_model.get_field(|context| context.handicraft);
     let enhance_mastery = view_model.get_field(|context| context.enhance_mastery);
     let upgrade_salvation = view_model.get_field(|context| context.upgrade_salvation);
+    let mvp_grade = view_model.get_field(|context| context.mvp_grade);
+    let pc_room = view_model.get_toggle(|context| context.pc_room);
 
     let handicraft_callback = view_model.handicraft_change_callback();
     let enhance_mastery_callback = view_model.enhance_mastery_change_callback();
     let upgrade_salvation_callback = view_model.upgrade_salvation_change_callback();
+    let mvp_grade_callback = view_model.mvp_grade_change_callback();
+    let pc_room_callback = view_model.pc_room_change_callback();
 
     let handicraft_tooltip = view_model.handicraft_tooltip();
     let enhance_mastery_tooltip = view_model.enhance_mastery_tooltip();
@@ -67,27 +157,145 @@ fn probability_fields() -> Vec<View> {
             (field(&spec_collection::UPGRADE_SALVATION, upgrade_salvation.clone(), upgrade_salvation_callback.clone()))
             (upgrade_salvation_tooltip)
         },
+        select_field(
+            &spec_collection::MVP_GRADE,
+            mvp_grade,
+            || {
+                starforce_membership::mvp_grades()
+                    .iter()
+                    .map(|grade| (grade.id.to_string(), grade.label.clone()))
+                    .collect()
+            },
+            mvp_grade_callback,
+        ),
+        toggle_field(
+            &spec_collection::PC_ROOM,
+            [constants::PC_ROOM_INACTIVE_LABEL, constants::PC_ROOM_ACTIVE_LABEL],
+            pc_room,
+            pc_room_callback,
+        ),
     ]
     .into_iter()
     .collect::<Vec<View>>()
     .join(|| view! { div(class="divider") })
 }
 
+fn sensitivity_fields() -> Vec<View> {
+    let view_model = use_context::<UpgradeContextViewModel>();
+    let sensitivity = view_model.sensitivity();
+    let sensitivity_shown = view_model.sensitivity_shown;
+    let sensitivity_toggle_callback = view_model.sensitivity_toggle_callback();
+
+    vec![view! {
+        button(class="btn btn-sm", on:click=sensitivity_toggle_callback) {
+            (move || if sensitivity_shown.get() {
+                constants::SENSITIVITY_HIDE_LABEL
+            } else {
+                constants::SENSITIVITY_SHOW_LABEL
+            })
+        }
+        (move || sensitivity.with(|rows| {
+            rows.as_deref().map(sensitivity_rows).unwrap_or_default()
+        }))
+    }]
+}
+
+fn sensitivity_rows(rows: &[SensitivityRow]) -> View {
+    let rows = rows.iter().map(sensitivity_row).collect::<Vec<View>>();
+
+    view! {
+        table(class="table table-xs") {
+            thead {
+                tr {
+                    th { (constants::SENSITIVITY_LEVEL_HEADER) }
+                    th { (constants::SENSITIVITY_MESO_HEADER) }
+                }
+            }
+            tbody { (rows) }
+        }
+    }
+}
+
+fn sensitivity_row(row: &SensitivityRow) -> View {
+    let level = sensitivity::level_text(row);
+    let savings = sensitivity::savings_text(row);
+
+    view! {
+        tr {
+            th { (level) }
+            td { (savings) }
+        }
+    }
+}
+
 fn equipment_fields() -> Vec<View> {
     let view_model = use_context::<UpgradeContextViewModel>();
 
     let equipment_level = view_model.get_field(|context| context.equipment_level);
+    let equipment_category = view_model.get_field(|context| context.equipment_category);
     let upgradeable_count = view_model.get_field(|context| context.upgradeable_count);
-    let trace_required = view_model.get_field(|context| context.trace_required);
+    let existing_successes = view_model.get_field(|context| context.existing_successes);
+    let golden_hammer_used = view_model.get_toggle(|context| context.golden_hammer_used);
+    let spell_trace = view_model.get_field(|context| context.spell_trace);
+    let trace_required = view_model.trace_required();
 
     let equipment_level_callback = view_model.equipment_level_change_callback();
+    let equipment_category_callback = view_model.equipment_category_change_callback();
     let upgradeable_count_callback = view_model.upgradeable_count_change_callback();
+    let existing_successes_callback = view_model.existing_successes_change_callback();
+    let golden_hammer_used_callback = view_model.golden_hammer_used_change_callback();
+    let spell_trace_callback = view_model.spell_trace_change_callback();
     let trace_required_callback = view_model.trace_required_change_callback();
 
+    let available_spell_traces = view_model.available_spell_traces();
+
     [
         field(&spec_collection::EQUIPMENT_LEVEL, equipment_level, equipment_level_callback),
+        select_field(
+            &spec_collection::EQUIPMENT_CATEGORY,
+            equipment_category,
+            || {
+                EQUIPMENT_CATEGORIES
+                    .iter()
+                    .map(|category| (category.id.to_string(), category.label.to_owned()))
+                    .collect()
+            },
+            equipment_category_callback,
+        ),
         field(&spec_collection::UPGRADEABLE_COUNT, upgradeable_count, upgradeable_count_callback),
-        field(&spec_collection::TRACE_REQUIRED, trace_required, trace_required_callback),
+        field(
+            &spec_collection::EXISTING_SUCCESSES,
+            existing_successes,
+            existing_successes_callback,
+        ),
+        toggle_field(
+            &spec_collection::GOLDEN_HAMMER_USED,
+            [constants::HAMMER_UNUSED_LABEL, constants::HAMMER_USED_LABEL],
+            golden_hammer_used,
+            golden_hammer_used_callback,
+        ),
+        select_field(
+            &spec_collection::SPELL_TRACE,
+            spell_trace,
+            move || {
+                available_spell_traces.with(|spell_traces| {
+                    spell_traces
+                        .iter()
+                        .map(|spell_trace| {
+                            (spell_trace.success_rate.to_string(), spell_trace.label.clone())
+                        })
+                        .collect()
+                })
+            },
+            spell_trace_callback,
+        ),
+        view! {
+            (move || field(
+                &spec_collection::TRACE_REQUIRED,
+                trace_required.get_clone(),
+                trace_required_callback.clone(),
+            ))
+        },
     ]
     .into_iter()
     .collect::<Vec<View>>()
@@ -98,20 +306,687 @@ fn price_fields() -> Vec<View> {
     let view_model = use_context::<UpgradeContextViewModel>();
 
     let trace_price = view_model.get_field(|context| context.trace_price);
+    let clean_slate_price = view_model.get_field(|context| context.clean_slate_price);
+    let innocent_price = view_model.get_field(|context| context.innocent_price);
+    let item_price = view_model.get_field(|context| context.item_price);
+    let golden_hammer_price = view_model.get_field(|context| context.golden_hammer_price);
+    let chaos_price = view_model.get_field(|context| context.chaos_price);
 
     let trace_price_callback = view_model.trace_price_change_callback();
+    let clean_slate_price_callback = view_model.clean_slate_price_change_callback();
+    let innocent_price_callback = view_model.innocent_price_change_callback();
+    let item_price_callback = view_model.item_price_change_callback();
+    let golden_hammer_price_callback = view_model.golden_hammer_price_change_callback();
+    let chaos_price_callback = view_model.chaos_price_change_callback();
 
     let trace_price_tooltip = view_model.trace_price_tooltip();
+    let clean_slate_price_tooltip = view_model.clean_slate_price_tooltip();
+    let innocent_price_tooltip = view_model.innocent_price_tooltip();
+    let item_price_tooltip = view_model.item_price_tooltip();
+    let golden_hammer_price_tooltip = view_model.golden_hammer_price_tooltip();
+    let chaos_price_tooltip = view_model.chaos_price_tooltip();
+
+    [
+        view! {
+            (field(&spec_collection::TRACE_PRICE, trace_price.clone(), trace_price_callback.clone()))
+            (trace_price_tooltip)
+        },
+        view! {
+            (field(&spec_collection::CLEAN_SLATE_PRICE, clean_slate_price.clone(), clean_slate_price_callback.clone()))
+            (clean_slate_price_tooltip)
+        },
+        view! {
+            (field(&spec_collection::INNOCENT_PRICE, innocent_price.clone(), innocent_price_callback.clone()))
+            (innocent_price_tooltip)
+        },
+        view! {
+            (field(&spec_collection::ITEM_PRICE, item_price.clone(), item_price_callback.clone()))
+            (item_price_tooltip)
+        },
+        view! {
+            (field(&spec_collection::GOLDEN_HAMMER_PRICE, golden_hammer_price.clone(), golden_hammer_price_callback.clone()))
+            (golden_hammer_price_tooltip)
+        },
+        view! {
+            (field(&spec_collection::CHAOS_PRICE, chaos_price.clone(), chaos_price_callback.clone()))
+            (chaos_price_tooltip)
+        },
+    ]
+    .into_iter()
+    .collect::<Vec<View>>()
+    .join(|| view! { div(class="divider") })
+}
+
+fn support_scroll_fields() -> Vec<View> {
+    let view_model = use_context::<UpgradeContextViewModel>();
+
+    let clean_slate_success_rate = view_model.get_field(|context| context.clean_slate_success_rate);
+    let innocent_success_rate = view_model.get_field(|context| context.innocent_success_rate);
+    let golden_hammer_success_rate =
+        view_model.get_field(|context| context.golden_hammer_success_rate);
+
+    let clean_slate_success_rate_callback = view_model.clean_slate_success_rate_change_callback();
+    let innocent_success_rate_callback = view_model.innocent_success_rate_change_callback();
+    let golden_hammer_success_rate_callback =
+        view_model.golden_hammer_success_rate_change_callback();
+
+    [
+        field(
+            &spec_collection::CLEAN_SLATE_SUCCESS_RATE,
+            clean_slate_success_rate,
+            clean_slate_success_rate_callback,
+        ),
+        field(
+            &spec_collection::INNOCENT_SUCCESS_RATE,
+            innocent_success_rate,
+            innocent_success_rate_callback,
+        ),
+        field(
+            &spec_collection::GOLDEN_HAMMER_SUCCESS_RATE,
+            golden_hammer_success_rate,
+            golden_hammer_success_rate_callback,
+        ),
+    ]
+    .into_iter()
+    .collect::<Vec<View>>()
+    .join(|| view! { div(class="divider") })
+}
+
+fn event_fields() -> Vec<View> {
+    let view_model = use_context::<UpgradeContextViewModel>();
 
-    [view! {
-        (field(&spec_collection::TRACE_PRICE, trace_price.clone(), trace_price_callback.clone()))
-        (trace_price_tooltip)
+    let event_active = view_model.get_toggle(|context| context.event_active);
+    let event_trace_discount = view_model.get_field(|context| context.event_trace_discount);
+    let event_success_bonus = view_model.get_field(|context| context.event_success_bonus);
+    let event_slot_protection = view_model.get_toggle(|context| context.event_slot_protection);
+
+    let event_active_callback = view_model.event_active_change_callback();
+    let event_trace_discount_callback = view_model.event_trace_discount_change_callback();
+    let event_success_bonus_callback = view_model.event_success_bonus_change_callback();
+    let event_slot_protection_callback = view_model.event_slot_protection_change_callback();
+
+    let event_trace_discount_tooltip = view_model.event_trace_discount_tooltip();
+    let event_success_bonus_tooltip = view_model.event_success_bonus_tooltip();
+
+    [
+        toggle_field(
+            &spec_collection::EVENT_ACTIVE,
+            [constants::EVENT_INACTIVE_LABEL, constants::EVENT_ACTIVE_LABEL],
+            event_active,
+            event_active_callback,
+        ),
+        view! {
+            (field(&spec_collection::EVENT_TRACE_DISCOUNT, event_trace_discount.clone(), event_trace_discount_callback.clone()))
+            (event_trace_discount_tooltip)
+        },
+        view! {
+            (field(&spec_collection::EVENT_SUCCESS_BONUS, event_success_bonus.clone(), event_success_bonus_callback.clone()))
+            (event_success_bonus_tooltip)
+        },
+        toggle_field(
+            &spec_collection::EVENT_SLOT_PROTECTION,
+            [constants::EVENT_INACTIVE_LABEL, constants::EVENT_ACTIVE_LABEL],
+            event_slot_protection,
+            event_slot_protection_callback,
+        ),
+    ]
+    .into_iter()
+    .collect::<Vec<View>>()
+    .join(|| view! { div(class="divider") })
+}
+
+fn success_rate_fields() -> Vec<View> {
+    let view_model = use_context::<UpgradeContextViewModel>();
+
+    spell_trace::spell_traces()
+        .iter()
+        .map(|spell_trace| {
+            let success_rate = view_model.success_rate(spell_trace);
+            memo_result(&spell_trace.label, success_rate, success_rate::breakdown_text)
+        })
+        .collect::<Vec<View>>()
+        .join(|| view! { div(class="divider") })
+}
+
+fn trace_required_fields() -> Vec<View> {
+    let view_model = use_context::<UpgradeContextViewModel>();
+
+    spell_trace::spell_traces()
+        .iter()
+        .map(|spell_trace| {
+            let trace_requirement = view_model.trace_requirement(spell_trace);
+            memo_result(&spell_trace.label, trace_requirement, spell_trace::trace_requirement_text)
+        })
+        .collect::<Vec<View>>()
+        .join(|| view! { div(class="divider") })
+}
+
+fn cost_fields() -> Vec<View> {
+    let view_model = use_context::<UpgradeContextViewModel>();
+    let expected_cost = view_model.expected_cost;
+
+    [
+        memo_result(constants::ATTEMPTS_LABEL, expected_cost, |cost| {
+            upgrade_cost::attempts_text(cost.attempts)
+        }),
+        memo_result(constants::SUCCESSES_LABEL, expected_cost, |cost| {
+            upgrade_cost::successes_text(cost.successes)
+        }),
+        memo_result(constants::TRACES_LABEL, expected_cost, |cost| {
+            upgrade_cost::traces_text(cost.traces)
+        }),
+        memo_result(constants::MESO_LABEL, expected_cost, |cost| {
+            upgrade_cost::meso_text(cost.meso)
+        }),
+    ]
+    .into_iter()
+    .collect::<Vec<View>>()
+    .join(|| view! { div(class="divider") })
+}
+
+fn distribution_fields() -> Vec<View> {
+    let view_model = use_context::<UpgradeContextViewModel>();
+    let cost_distribution = view_model.cost_distribution;
+    let within_trace_limit = view_model.within_trace_limit();
+
+    let trace_limit = view_model.get_field(|context| context.trace_limit);
+    let trace_limit_callback = view_model.trace_limit_change_callback();
+
+    PERCENTILES
+        .into_iter()
+        .map(|percentile| {
+            result(upgrade_cost::percentile_label(percentile), move || {
+                cost_distribution.with(|distribution| {
+                    distribution.as_ref().map(|distribution| {
+                        upgrade_cost::percentile_text(
+                            distribution.traces_percentile(percentile),
+                            distribution.meso_percentile(percentile),
+                        )
+                    })
+                })
+            })
+        })
+        .chain([
+            field(&spec_collection::TRACE_LIMIT, trace_limit, trace_limit_callback),
+            memo_result(constants::WITHIN_TRACE_LIMIT_LABEL, within_trace_limit, |probability| {
+                upgrade_cost::probability_text(*probability)
+            }),
+        ])
+        .collect::<Vec<View>>()
+        .join(|| view! { div(class="divider") })
+}
+
+fn strategy_fields() -> Vec<View> {
+    let view_model = use_context::<UpgradeContextViewModel>();
+    let strategy = view_model.strategy;
+
+    let target_stat_kind = view_model.get_field(|context| context.target_stat_kind);
+    let target_stat = view_model.get_field(|context| context.target_stat);
+    let target_stat_kind_callback = view_model.target_stat_kind_change_callback();
+    let target_stat_callback = view_model.target_stat_change_callback();
+    let available_stats = view_model.available_stats();
+
+    [
+        select_field(
+            &spec_collection::TARGET_STAT_KIND,
+            target_stat_kind,
+            move || {
+                available_stats.with(|stat_kinds| {
+                    stat_kinds
+                        .iter()
+                        .map(|stat_kind| (stat_kind.id.to_string(), stat_kind.label.to_owned()))
+                        .collect()
+                })
+            },
+            target_stat_kind_callback,
+        ),
+        field(&spec_collection::TARGET_STAT, target_stat, target_stat_callback),
+        memo_result(constants::STRATEGY_MESO_LABEL, strategy, |strategy| {
+            upgrade_cost::meso_text(strategy.expected_meso)
+        }),
+        memo_result(constants::ITEM_SUCCESS_LABEL, strategy, |strategy| {
+            upgrade_cost::probability_text(strategy.item_success)
+        }),
+        memo_result(constants::CLEAN_SLATES_LABEL, strategy, |strategy| {
+            strategy::scroll_count_text(strategy.expected_clean_slates)
+        }),
+        memo_result(constants::INNOCENTS_LABEL, strategy, |strategy| {
+            strategy::scroll_count_text(strategy.expected_innocents)
+        }),
+        view! {
+            (move || strategy.with(|strategy| {
+                strategy.as_ref().map(|strategy| policy_rows(&strategy.policy())).unwrap_or_default()
+            }))
+        },
+    ]
+    .into_iter()
+    .collect::<Vec<View>>()
+    .join(|| view! { div(class="divider") })
+}
+
+fn target_fields() -> Vec<View> {
+    let view_model = use_context::<UpgradeContextViewModel>();
+    let target_plans = view_model.target_plans();
+
+    vec![view! {
+        (move || target_plans.with(|plans| {
+            plans.as_deref().map(target_plan_rows).unwrap_or_default()
+        }))
     }]
+}
+
+fn target_plan_rows(plans: &[TargetPlan]) -> View {
+    let rows = plans.iter().map(target_plan_row).collect::<Vec<View>>();
+
+    view! {
+        table(class="table table-xs") {
+            thead {
+                tr {
+                    th { (constants::TARGET_STRATEGY_HEADER) }
+                    th { (constants::TARGET_PROBABILITY_HEADER) }
+                    th { (constants::TARGET_MESO_HEADER) }
+                }
+            }
+            tbody { (rows) }
+        }
+    }
+}
+
+fn target_plan_row(plan: &TargetPlan) -> View {
+    let label = plan.label;
+    let probability = upgrade_cost::probability_text(plan.probability);
+    let meso = plan
+        .expected_meso
+        .map_or_else(|| constants::EMPTY_RESULT.to_owned(), upgrade_cost::meso_text);
+
+    view! {
+        tr {
+            th { (label) }
+            td { (probability) }
+            td { (meso) }
+        }
+    }
+}
+
+fn budget_fields() -> Vec<View> {
+    let view_model = use_context::<UpgradeContextViewModel>();
+    let budget_plan = view_model.budget_plan();
+
+    let meso_budget = view_model.get_field(|context| context.meso_budget);
+    let meso_budget_callback = view_model.meso_budget_change_callback();
+    let meso_budget_tooltip = view_model.meso_budget_tooltip();
+
+    [
+        view! {
+            (field(&spec_collection::MESO_BUDGET, meso_budget.clone(), meso_budget_callback.clone()))
+            (meso_budget_tooltip)
+        },
+        memo_result(constants::BUDGET_PROBABILITY_LABEL, budget_plan, budget::probability_text),
+        view! {
+            label(class="label") { (constants::BUDGET_POLICY_LABEL) }
+            (move || budget_plan.with(|plan| {
+                plan.as_ref().map(|plan| policy_rows(&plan.policy)).unwrap_or_default()
+            }))
+        },
+    ]
     .into_iter()
     .collect::<Vec<View>>()
     .join(|| view! { div(class="divider") })
 }
 
+fn salvation_fields() -> Vec<View> {
+    let view_model = use_context::<UpgradeContextViewModel>();
+    let salvation_comparison = view_model.salvation_comparison();
+
+    [
+        memo_result(
+            constants::SALVATION_SUCCESSES_LABEL,
+            salvation_comparison,
+            upgrade_cost::salvation_successes_text,
+        ),
+        result(constants::SALVATION_TRACES_LABEL, move || {
+            salvation_comparison
+                .with(|outcomes| outcomes.as_ref().and_then(upgrade_cost::salvation_traces_text))
+        }),
+        result(constants::SALVATION_MESO_LABEL, move || {
+            salvation_comparison
+                .with(|outcomes| outcomes.as_ref().and_then(upgrade_cost::salvation_meso_text))
+        }),
+    ]
+    .into_iter()
+    .collect::<Vec<View>>()
+    .join(|| view! { div(class="divider") })
+}
+
+fn event_comparison_fields() -> Vec<View> {
+    let view_model = use_context::<UpgradeContextViewModel>();
+    let event_comparison = view_model.event_comparison();
+
+    [
+        memo_result(constants::EVENT_TRACES_LABEL, event_comparison, event::traces_comparison_text),
+        memo_result(constants::EVENT_MESO_LABEL, event_comparison, event::meso_comparison_text),
+        memo_result(constants::EVENT_SAVINGS_LABEL, event_comparison, event::meso_savings_text),
+        result(constants::EVENT_TARGET_MESO_LABEL, move || {
+            event_comparison
+                .with(|comparison| comparison.as_ref().and_then(event::target_meso_comparison_text))
+        }),
+        result(constants::EVENT_TARGET_SAVINGS_LABEL, move || {
+            event_comparison
+                .with(|comparison| comparison.as_ref().and_then(event::target_meso_savings_text))
+        }),
+    ]
+    .into_iter()
+    .collect::<Vec<View>>()
+    .join(|| view! { div(class="divider") })
+}
+
+fn hammer_fields() -> Vec<View> {
+    let view_model = use_context::<UpgradeContextViewModel>();
+    let hammer_slot = view_model.hammer_slot();
+
+    [
+        memo_result(constants::HAMMERS_LABEL, hammer_slot, |slot| {
+            strategy::scroll_count_text(slot.expected_hammers)
+        }),
+        memo_result(constants::HAMMER_STAT_LABEL, hammer_slot, golden_hammer::stat_gain_text),
+        memo_result(constants::HAMMER_MESO_LABEL, hammer_slot, golden_hammer::slot_meso_text),
+        result(constants::HAMMER_MESO_PER_STAT_LABEL, move || {
+            hammer_slot.with(|slot| slot.as_ref().and_then(golden_hammer::meso_per_stat_text))
+        }),
+        result(constants::HAMMER_TARGET_MESO_LABEL, move || {
+            hammer_slot.with(|slot| slot.as_ref().and_then(golden_hammer::target_meso_text))
+        }),
+    ]
+    .into_iter()
+    .collect::<Vec<View>>()
+    .join(|| view! { div(class="divider") })
+}
+
+fn chaos_fields() -> Vec<View> {
+    let view_model = use_context::<UpgradeContextViewModel>();
+    let chaos_outcome = view_model.chaos_outcome();
+
+    let chaos_scroll = view_model.get_field(|context| context.chaos_scroll);
+    let chaos_scroll_callback = view_model.chaos_scroll_change_callback();
+
+    [
+        select_field(
+            &spec_collection::CHAOS_SCROLL,
+            chaos_scroll,
+            || {
+                chaos::chaos_scrolls()
+                    .iter()
+                    .map(|scroll| (scroll.id.to_string(), scroll.label.clone()))
+                    .collect()
+            },
+            chaos_scroll_callback,
+        ),
+        memo_result(constants::CHAOS_ATTACK_MEAN_LABEL, chaos_outcome, |outcome| {
+            chaos::mean_text(&outcome.attack, outcome.attack_offset)
+        }),
+        memo_result(constants::CHAOS_ALL_STATS_MEAN_LABEL, chaos_outcome, |outcome| {
+            chaos::mean_text(&outcome.all_stats, outcome.all_stats_offset)
+        }),
+        memo_result(constants::CHAOS_PASS_MESO_LABEL, chaos_outcome, |outcome| {
+            upgrade_cost::meso_text(outcome.pass_meso)
+        }),
+        result(constants::CHAOS_TARGET_PROBABILITY_LABEL, move || {
+            chaos_outcome.with(|outcome| {
+                outcome.as_ref()?.target_probability.map(upgrade_cost::probability_text)
+            })
+        }),
+        result(constants::CHAOS_TARGET_MESO_LABEL, move || {
+            chaos_outcome.with(|outcome| outcome.as_ref()?.target_meso.map(upgrade_cost::meso_text))
+        }),
+        view! {
+            label(class="label") { (constants::CHAOS_ATTACK_LABEL) }
+            (move || chaos_outcome.with(|outcome| {
+                outcome.as_ref().map(chaos_attack_rows).unwrap_or_default()
+            }))
+        },
+    ]
+    .into_iter()
+    .collect::<Vec<View>>()
+    .join(|| view! { div(class="divider") })
+}
+
+fn pet_fields() -> Vec<View> {
+    let view_model = use_context::<UpgradeContextViewModel>();
+    let pet_outcome = view_model.pet_outcome();
+
+    let pet_scroll = view_model.get_field(|context| context.pet_scroll);
+    let pet_upgradeable_count = view_model.get_field(|context| context.pet_upgradeable_count);
+    let pet_scroll_price = view_model.get_field(|context| context.pet_scroll_price);
+    let cash_exchange_rate = view_model.get_field(|context| context.cash_exchange_rate);
+
+    let pet_scroll_callback = view_model.pet_scroll_change_callback();
+    let pet_upgradeable_count_callback = view_model.pet_upgradeable_count_change_callback();
+    let pet_scroll_price_callback = view_model.pet_scroll_price_change_callback();
+    let cash_exchange_rate_callback = view_model.cash_exchange_rate_change_callback();
+
+    let pet_scroll_price_tooltip = view_model.pet_scroll_price_tooltip();
+    let cash_exchange_rate_tooltip = view_model.cash_exchange_rate_tooltip();
+
+    [
+        select_field(
+            &spec_collection::PET_SCROLL,
+            pet_scroll,
+            || {
+                pet_equipment::pet_scrolls()
+                    .iter()
+                    .map(|scroll| (scroll.id.to_string(), scroll.label.clone()))
+                    .collect()
+            },
+            pet_scroll_callback,
+        ),
+        field(
+            &spec_collection::PET_UPGRADEABLE_COUNT,
+            pet_upgradeable_count,
+            pet_upgradeable_count_callback,
+        ),
+        view! {
+            (field(&spec_collection::PET_SCROLL_PRICE, pet_scroll_price.clone(), pet_scroll_price_callback.clone()))
+            (pet_scroll_price_tooltip)
+        },
+        view! {
+            (field(&spec_collection::CASH_EXCHANGE_RATE, cash_exchange_rate.clone(), cash_exchange_rate_callback.clone()))
+            (cash_exchange_rate_tooltip)
+        },
+        memo_result(constants::PET_ATTACK_LABEL, pet_outcome, pet_equipment::attack_text),
+        memo_result(constants::PET_ATTEMPTS_LABEL, pet_outcome, |outcome| {
+            upgrade_cost::attempts_text(outcome.expected_attempts())
+        }),
+        memo_result(constants::PET_MESO_LABEL, pet_outcome, |outcome| {
+            upgrade_cost::meso_text(outcome.expected_meso())
+        }),
+    ]
+    .into_iter()
+    .chain(PERCENTILES.into_iter().map(|percentile| {
+        result(upgrade_cost::percentile_label(percentile), move || {
+            pet_outcome.with(|outcome| {
+                outcome
+                    .as_ref()
+                    .map(|outcome| pet_equipment::percentile_text(outcome, percentile))
+            })
+        })
+    }))
+    .chain([view! {
+        label(class="label") { (constants::PET_ATTACK_DISTRIBUTION_LABEL) }
+        (move || pet_outcome.with(|outcome| {
+            outcome.as_ref().map(|outcome| stat_rows(&outcome.attack)).unwrap_or_default()
+        }))
+    }])
+    .collect::<Vec<View>>()
+    .join(|| view! { div(class="divider") })
+}
+
+fn chaos_attack_rows(outcome: &ChaosOutcome) -> View {
+    outcome
+        .attack
+        .support()
+        .map(|(value, probability)| {
+            let text = chaos::change_text(value, outcome.attack_offset, probability);
+            view! { p { (text) } }
+        })
+        .collect::<Vec<View>>()
+        .into()
+}
+
+fn reset_fields() -> Vec<View> {
+    let view_model = use_context::<UpgradeContextViewModel>();
+    let reset_plan = view_model.reset_plan();
+
+    [
+        memo_result(constants::RESET_THRESHOLD_LABEL, reset_plan, innocent::threshold_text),
+        memo_result(constants::KEEP_PROBABILITY_LABEL, reset_plan, |plan| {
+            upgrade_cost::probability_text(plan.keep_probability)
+        }),
+        memo_result(constants::RESET_MESO_LABEL, reset_plan, |plan| {
+            upgrade_cost::meso_text(plan.expected_meso)
+        }),
+        memo_result(constants::INNOCENTS_LABEL, reset_plan, |plan| {
+            strategy::scroll_count_text(plan.expected_innocents)
+        }),
+        memo_result(constants::FINAL_STAT_MEAN_LABEL, reset_plan, innocent::final_stat_mean_text),
+        view! {
+            label(class="label") { (constants::FINAL_STAT_LABEL) }
+            (move || reset_plan.with(|plan| {
+                plan.as_ref().map(|plan| stat_rows(&plan.final_stat)).unwrap_or_default()
+            }))
+        },
+    ]
+    .into_iter()
+    .collect::<Vec<View>>()
+    .join(|| view! { div(class="divider") })
+}
+
+fn stat_outcome_fields() -> Vec<View> {
+    let view_model = use_context::<UpgradeContextViewModel>();
+    let selected_stat_outcome = view_model.selected_stat_outcome();
+
+    spell_trace::spell_traces()
+        .iter()
+        .map(|spell_trace| {
+            let stat_outcome = view_model.stat_outcome(spell_trace);
+            memo_result(&spell_trace.label, stat_outcome, stat_outcome::outcome_text)
+        })
+        .chain([view! {
+            label(class="label") { (constants::SELECTED_STAT_LABEL) }
+            (move || selected_stat_outcome.with(|outcome| {
+                outcome.as_ref().map(|outcome| stat_rows(&outcome.distribution)).unwrap_or_default()
+            }))
+        }])
+        .collect::<Vec<View>>()
+        .join(|| view! { div(class="divider") })
+}
+
+fn stat_rows(distribution: &Distribution) -> View {
+    distribution
+        .support()
+        .map(|(stat, probability)| {
+            let text = stat_outcome::stat_text(stat, probability);
+            view! { p { (text) } }
+        })
+        .collect::<Vec<View>>()
+        .into()
+}
+
+fn simulation_fields() -> Vec<View> {
+    let view_model = use_context::<UpgradeContextViewModel>();
+    let expected_cost = view_model.expected_cost;
+    let strategy = view_model.strategy;
+    let upgrade_simulation = view_model.upgrade_simulation();
+    let strategy_simulation = view_model.strategy_simulation();
+    let simulation_shown = view_model.simulation_shown;
+    let simulation_toggle_callback = view_model.simulation_toggle_callback();
+
+    let simulation_runs = view_model.get_field(|context| context.simulation_runs);
+    let simulation_seed = view_model.get_field(|context| context.simulation_seed);
+    let simulation_runs_callback = view_model.simulation_runs_change_callback();
+    let simulation_seed_callback = view_model.simulation_seed_change_callback();
+
+    [
+        field(&spec_collection::SIMULATION_RUNS, simulation_runs, simulation_runs_callback),
+        field(&spec_collection::SIMULATION_SEED, simulation_seed, simulation_seed_callback),
+        view! {
+            button(class="btn btn-sm", on:click=simulation_toggle_callback) {
+                (move || if simulation_shown.get() {
+                    constants::SIMULATION_HIDE_LABEL
+                } else {
+                    constants::SIMULATION_SHOW_LABEL
+                })
+            }
+        },
+        result(constants::SIMULATION_MEAN_LABEL, move || {
+            let simulated = upgrade_simulation.with(|simulation| {
+                simulation.as_ref().map(|simulation| {
+                    upgrade_cost::percentile_text(simulation.mean_traces(), simulation.mean_meso())
+                })
+            })?;
+            let analytic = expected_cost.with(|cost| {
+                cost.as_ref().map(|cost| upgrade_cost::percentile_text(cost.traces, cost.meso))
+            })?;
+
+            Some(simulation::comparison_text(&simulated, &analytic))
+        }),
+    ]
+    .into_iter()
+    .chain(PERCENTILES.into_iter().map(|percentile| {
+        result(upgrade_cost::percentile_label(percentile), move || {
+            upgrade_simulation.with(|simulation| {
+                simulation.as_ref().map(|simulation| {
+                    upgrade_cost::percentile_text(
+                        simulation.traces_percentile(percentile),
+                        simulation.meso_percentile(percentile),
+                    )
+                })
+            })
+        })
+    }))
+    .chain([result(constants::STRATEGY_SIMULATION_LABEL, move || {
+        let analytic = strategy.with(|strategy| {
+            strategy.as_ref().map(|strategy| upgrade_cost::meso_text(strategy.expected_meso))
+        })?;
+
+        strategy_simulation.with(|simulation| {
+            simulation
+                .as_ref()
+                .map(|simulation| simulation::strategy_simulation_text(simulation, &analytic))
+        })
+    })])
+    .collect::<Vec<View>>()
+    .join(|| view! { div(class="divider") })
+}
+
+fn policy_rows(rows: &[PolicyRow]) -> View {
+    let rows = rows.iter().map(policy_row).collect::<Vec<View>>();
+
+    view! {
+        table(class="table table-xs") {
+            tbody { (rows) }
+        }
+    }
+}
+
+fn policy_row(row: &PolicyRow) -> View {
+    let label = strategy::row_label(row);
+    let ranges = row
+        .ranges
+        .iter()
+        .map(|range| {
+            let text = strategy::range_text(range);
+            view! { p { (text) } }
+        })
+        .collect::<Vec<View>>();
+
+    view! {
+        tr {
+            th { (label) }
+            td { (ranges) }
+        }
+    }
+}
+
 #[component]
 fn CharacterSearch() -> View {
     let view_model = use_context::<UpgradeContextViewModel>();
@@ -134,25 +1009,3 @@ fn CharacterSearch() -> View {
         }
     }
 }
-
-fn field(spec: &Spec, value: Option<String>, callback: Callback) -> View {
-    let label = spec.label;
-    let placeholder = spec.placeholder;
-    let min = spec.min.to_string();
-    let max = spec.max.to_string();
-
-    view! {
-        label(class="label", r#for=label) { (label) }
-        input(
-            r#type="number",
-            id=label,
-            class="input validator",
-            required=true,
-            placeholder=placeholder,
-            value=value,
-            min=min,
-            max=max,
-            on:change=callback
-        ) {}
-    }
-}